@@ -33,16 +33,52 @@ typedef struct ResolverPtr ResolverPtr;
 typedef struct Bip353Result {
     /** Whether the resolution was successful */
     int success;
-    
+
     /** The URI (BIP-21) */
     char* uri;
-    
+
     /** The payment type */
     char* payment_type;
-    
+
     /** Whether the payment is reusable */
     int is_reusable;
-    
+
+    /** Whether the bolt11_* fields below are populated */
+    int has_bolt11_details;
+
+    /** The invoice amount in millisatoshis, or -1 for an "any amount" invoice */
+    long long bolt11_amount_msat;
+
+    /** The invoice payment hash, hex-encoded (NULL if not a Lightning invoice) */
+    char* bolt11_payment_hash;
+
+    /** The payee's node id, hex-encoded (NULL if not recoverable from the invoice) */
+    char* bolt11_payee_pubkey;
+
+    /** Seconds after the invoice's timestamp that it remains valid for */
+    unsigned long long bolt11_expiry_secs;
+
+    /** Whether the invoice has already expired */
+    int bolt11_is_expired;
+
+    /** Whether the offer_* fields below are populated */
+    int has_offer_details;
+
+    /** The offer's fixed amount in millisatoshis, or -1 if the payer must choose */
+    long long offer_amount_msat;
+
+    /** Whether the payer must choose the amount when requesting an invoice */
+    int offer_amount_is_configurable;
+
+    /** The offer's signing node id, hex-encoded (NULL if unset) */
+    char* offer_signing_pubkey;
+
+    /** The serialized DNSSEC proof chain, or NULL if one wasn't captured */
+    unsigned char* dnssec_proof;
+
+    /** Length, in bytes, of dnssec_proof */
+    size_t dnssec_proof_len;
+
     /** Error message (if any) */
     char* error;
 } Bip353Result;
@@ -62,9 +98,29 @@ ResolverPtr* bip353_resolver_create(void);
  */
 ResolverPtr* bip353_resolver_create_with_network(const char* network_name);
 
+/**
+ * Create a new resolver whose network is auto-detected from a running
+ * bitcoind instance via getblockchaininfo (requires the bitcoind-rpc feature)
+ *
+ * @param rpc_url The bitcoind RPC URL (e.g. "http://127.0.0.1:8332")
+ * @param rpc_user The RPC username
+ * @param rpc_password The RPC password
+ * @return A pointer to the resolver, or NULL on error
+ */
+ResolverPtr* bip353_resolver_create_from_bitcoind_rpc(const char* rpc_url, const char* rpc_user, const char* rpc_password);
+
+/**
+ * Create a new resolver with default configuration and an in-memory
+ * resolution cache bounded to capacity entries
+ *
+ * @param capacity Maximum number of resolved addresses to cache
+ * @return A pointer to the resolver, or NULL on error
+ */
+ResolverPtr* bip353_resolver_create_with_cache(size_t capacity);
+
 /**
  * Free a resolver
- * 
+ *
  * @param ptr The resolver to free
  */
 void bip353_resolver_free(ResolverPtr* ptr);
@@ -88,13 +144,99 @@ Bip353Result* bip353_resolve_address(const ResolverPtr* ptr, const char* address
  */
 Bip353Result* bip353_resolve(const ResolverPtr* ptr, const char* user, const char* domain);
 
+/**
+ * Resolve a human-readable Bitcoin address for a specific amount, for
+ * addresses that resolve to a configurable-amount method such as LNURL-Pay
+ *
+ * @param ptr The resolver
+ * @param address The address to resolve (e.g. "₿user@domain")
+ * @param amount_sat The amount the payer intends to send, in satoshis
+ * @return A pointer to the result, or NULL on error
+ */
+Bip353Result* bip353_resolve_for_amount(const ResolverPtr* ptr, const char* address, unsigned long long amount_sat);
+
+/**
+ * Resolve a human-readable Bitcoin address and capture the raw DNSSEC proof
+ * chain alongside it (available via the result's dnssec_proof field), for
+ * offline/air-gapped verification later with bip353_verify_proof
+ *
+ * @param ptr The resolver
+ * @param address The address to resolve (e.g. "₿user@domain")
+ * @return A pointer to the result, or NULL on error
+ */
+Bip353Result* bip353_resolve_with_proof(const ResolverPtr* ptr, const char* address);
+
+/**
+ * Opaque handle for a pending bip353_resolve_async lookup
+ */
+typedef struct Bip353CancelHandle Bip353CancelHandle;
+
+/**
+ * Callback invoked when a bip353_resolve_async lookup completes. The
+ * callback must free result with bip353_result_free exactly once.
+ */
+typedef void (*Bip353ResolveCallback)(Bip353Result* result, void* user_data);
+
+/**
+ * Resolve a human-readable Bitcoin address without blocking the calling
+ * thread: spawns the resolution in the background and returns immediately,
+ * invoking callback with the result once it's ready
+ *
+ * @param ptr The resolver
+ * @param address The address to resolve (e.g. "₿user@domain")
+ * @param callback Invoked with the result; must free it with bip353_result_free
+ * @param user_data Opaque pointer passed through unchanged to callback
+ * @return A cancellation handle, which must eventually be freed with bip353_cancel, or NULL on error
+ */
+Bip353CancelHandle* bip353_resolve_async(const ResolverPtr* ptr, const char* address, Bip353ResolveCallback callback, void* user_data);
+
+/**
+ * Cancel a pending bip353_resolve_async lookup (a harmless no-op if it has
+ * already completed) and free its handle
+ *
+ * @param handle The handle to cancel and free
+ */
+void bip353_cancel(Bip353CancelHandle* handle);
+
+/**
+ * Re-validate a previously captured DNSSEC proof with no network access,
+ * asserting it attests to the bitcoin: URI in expected_txt
+ *
+ * @param proof The serialized DNSSEC proof chain
+ * @param proof_len Length, in bytes, of proof
+ * @param address The address the proof was captured for (e.g. "₿user@domain")
+ * @param expected_txt The bitcoin: URI the proof is expected to attest to
+ * @return A pointer to the result, or NULL on error
+ */
+Bip353Result* bip353_verify_proof(const unsigned char* proof, size_t proof_len, const char* address, const char* expected_txt);
+
 /**
  * Free a result
- * 
+ *
  * @param ptr The result to free
  */
 void bip353_result_free(Bip353Result* ptr);
 
+/**
+ * Resolve a human-readable Bitcoin address into every payment method the
+ * record advertises, instead of the single preferred method bip353_resolve_address
+ * collapses them into
+ *
+ * @param ptr The resolver
+ * @param address The address to resolve (e.g. "₿user@domain")
+ * @param out_len Receives the number of results on success, or 0 on error
+ * @return An array of result pointers (free with bip353_result_array_free), or NULL on error
+ */
+Bip353Result** bip353_resolve_all(const ResolverPtr* ptr, const char* address, size_t* out_len);
+
+/**
+ * Free an array of results returned by bip353_resolve_all
+ *
+ * @param ptr The result array to free
+ * @param len The number of results in the array
+ */
+void bip353_result_array_free(Bip353Result** ptr, size_t len);
+
 /**
  * Parse a human-readable Bitcoin address into user and domain parts
  * 
@@ -3,6 +3,9 @@
 use std::net::{SocketAddr, IpAddr, Ipv4Addr};
 use std::time::Duration;
 
+#[cfg(feature = "bitcoind-rpc")]
+use crate::Bip353Error;
+
 /// Configuration for BIP-353 resolver
 #[derive(Debug, Clone)]
 pub struct ResolverConfig {
@@ -21,6 +24,21 @@ pub struct ResolverConfig {
     
     /// Network to use for parsing payment instructions
     pub network: bitcoin::Network,
+
+    /// Maximum number of resolved addresses the resolver's in-memory cache
+    /// will hold, or `None` to resolve fresh every time (the default)
+    pub cache_capacity: Option<usize>,
+
+    /// Upper bound on how long a cache entry may live, regardless of the TTL
+    /// the record itself advertised - caps how long a hostile or misbehaving
+    /// authoritative server can pin stale payment data. `None` leaves each
+    /// entry's own TTL unbounded (the default).
+    pub max_cache_ttl: Option<Duration>,
+
+    /// Fraction of a cache entry's TTL that must have elapsed before
+    /// `AddressWarning::StaleRecord` is raised for it, e.g. `0.8` warns once
+    /// 80% of the entry's TTL has passed rather than waiting for full expiry
+    pub stale_record_fraction: f64,
 }
 
 impl Default for ResolverConfig {
@@ -32,6 +50,9 @@ impl Default for ResolverConfig {
             timeout_ms: 5000, // 5 second timeout
             allow_http_fallback: true,
             network: bitcoin::Network::Bitcoin,
+            cache_capacity: None,
+            max_cache_ttl: None,
+            stale_record_fraction: 0.8,
         }
     }
 }
@@ -90,9 +111,61 @@ impl ResolverConfig {
         self.network = network;
         self
     }
-    
+
+    /// Enable the resolver's in-memory resolution cache, bounded to at most
+    /// `capacity` entries (least-recently-used entry evicted first once full)
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Cap how long any single cache entry may be served for, regardless of
+    /// the TTL the record itself advertised
+    pub fn with_max_cache_ttl(mut self, max_ttl: Duration) -> Self {
+        self.max_cache_ttl = Some(max_ttl);
+        self
+    }
+
+    /// Set the fraction of a cache entry's TTL that must elapse before
+    /// `AddressWarning::StaleRecord` is raised for it
+    pub fn with_stale_record_fraction(mut self, fraction: f64) -> Self {
+        self.stale_record_fraction = fraction;
+        self
+    }
+
     /// Get the timeout as a Duration
     pub fn timeout(&self) -> Duration {
         Duration::from_millis(self.timeout_ms)
     }
+
+    /// Build a config whose network matches a running `bitcoind` instance
+    ///
+    /// Calls `getblockchaininfo` against the node at `url` and maps its
+    /// `chain` field to the corresponding `bitcoin::Network`, so the
+    /// resolver's own network-validation of resolved addresses always
+    /// matches the node it's attached to, instead of relying on the
+    /// integrator to restate the chain by hand.
+    #[cfg(feature = "bitcoind-rpc")]
+    pub fn from_bitcoind_rpc(url: &str, auth: bitcoincore_rpc::Auth) -> Result<Self, Bip353Error> {
+        use bitcoincore_rpc::RpcApi;
+
+        let client = bitcoincore_rpc::Client::new(url, auth)
+            .map_err(|e| Bip353Error::NetworkError(format!("Failed to connect to bitcoind: {}", e)))?;
+
+        let info = client.get_blockchain_info()
+            .map_err(|e| Bip353Error::NetworkError(format!("getblockchaininfo failed: {}", e)))?;
+
+        let network = match info.chain.to_string().as_str() {
+            "bitcoin" | "main" => bitcoin::Network::Bitcoin,
+            "testnet" | "test" => bitcoin::Network::Testnet,
+            "signet" => bitcoin::Network::Signet,
+            "regtest" => bitcoin::Network::Regtest,
+            other => return Err(Bip353Error::NetworkError(format!("Unrecognized chain from bitcoind: {}", other))),
+        };
+
+        Ok(Self {
+            network,
+            ..Default::default()
+        })
+    }
 }
\ No newline at end of file
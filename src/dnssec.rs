@@ -0,0 +1,127 @@
+//! DNSSEC proof export and offline re-verification
+//!
+//! The resolver already uses `dnssec-prover` internally to validate BIP-353
+//! TXT records, but by default the proof chain is thrown away once the
+//! boolean "DNSSEC OK" result has been extracted. This module lets an
+//! online host capture the raw proof (the RRSIG/DNSKEY/DS records from the
+//! root down to the TXT record) and ship it to an offline/air-gapped
+//! signer, which can re-validate it with `verify_proof` and no network
+//! access of its own.
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use dnssec_prover::query::build_txt_proof;
+use dnssec_prover::rr::Name;
+use dnssec_prover::ser::parse_rr_stream;
+use dnssec_prover::validation::verify_rr_stream;
+
+use crate::{Bip353Error, PaymentInfo};
+
+/// Build the DNS name BIP-353 expects a payment instruction TXT record under
+fn bip353_name(user: &str, domain: &str) -> Result<Name, Bip353Error> {
+    Name::from_str(&format!("{}.user._bitcoin-payment.{}.", user, domain))
+        .map_err(|_| Bip353Error::InvalidAddress("Invalid BIP-353 DNS name".into()))
+}
+
+/// Resolve `user@domain` and capture the raw DNSSEC proof chain alongside
+/// the resolved `PaymentInfo`, so it can be archived or shipped to an
+/// air-gapped signer for independent verification
+pub async fn resolve_with_proof(
+    dns_resolver: SocketAddr,
+    user: &str,
+    domain: &str,
+) -> Result<(PaymentInfo, Vec<u8>), Bip353Error> {
+    let name = bip353_name(user, domain)?;
+
+    let (proof, wallclock) = build_txt_proof(dns_resolver, &name)
+        .await
+        .map_err(|e| Bip353Error::DnsError(format!("Failed to build DNSSEC proof: {}", e)))?;
+
+    let info = verify_proof_at(&proof, user, domain, wallclock, None)?;
+
+    Ok((info, proof))
+}
+
+/// Fetch the DNS TTL that should bound how long a cached resolution of
+/// `user@domain` may be kept, derived from the record's own DNSSEC proof
+/// stream rather than a synthetic constant
+///
+/// This builds and validates the same proof chain `resolve_with_proof`
+/// does, but only to read off its TTL - `max_cache_ttl` is the minimum TTL
+/// across the records in the stream, i.e. the actual DNS TTL the zone
+/// operator attached to this answer. This is deliberately *not*
+/// `verified.expires`, which is when the RRSIG *signatures* expire
+/// (typically days/weeks out) and has nothing to do with how fresh the
+/// record itself is.
+pub async fn fetch_ttl(
+    dns_resolver: SocketAddr,
+    user: &str,
+    domain: &str,
+) -> Result<Duration, Bip353Error> {
+    let name = bip353_name(user, domain)?;
+
+    let (proof, wallclock) = build_txt_proof(dns_resolver, &name)
+        .await
+        .map_err(|e| Bip353Error::DnsError(format!("Failed to build DNSSEC proof: {}", e)))?;
+
+    let rrs = parse_rr_stream(&proof)
+        .map_err(|_| Bip353Error::DnssecError("Failed to parse DNSSEC proof".into()))?;
+
+    let verified = verify_rr_stream(&rrs, wallclock)
+        .map_err(|e| Bip353Error::DnssecError(format!("DNSSEC proof validation failed: {:?}", e)))?;
+
+    Ok(Duration::from_secs(verified.max_cache_ttl as u64))
+}
+
+/// Re-validate a previously captured DNSSEC proof with no network access,
+/// returning the `PaymentInfo` it attests to for `user@domain`
+///
+/// This lets an online host resolve `₿user@domain`, ship the compact proof
+/// blob and the `bitcoin:` URI it expects to an air-gapped signer, and have
+/// the signer independently confirm the proof attests to that exact
+/// instruction - not just *some* validly-signed TXT record - before signing
+/// anything. `expected_txt` is compared verbatim against the resolved
+/// `bitcoin:` URI; a mismatch fails closed with `Bip353Error::DnssecError`.
+pub fn verify_proof(proof: &[u8], user: &str, domain: &str, expected_txt: &str) -> Result<PaymentInfo, Bip353Error> {
+    let wallclock = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| Bip353Error::InvalidRecord("System clock is before the Unix epoch".into()))?
+        .as_secs();
+
+    verify_proof_at(proof, user, domain, wallclock, Some(expected_txt))
+}
+
+fn verify_proof_at(
+    proof: &[u8],
+    user: &str,
+    domain: &str,
+    wallclock: u64,
+    expected_txt: Option<&str>,
+) -> Result<PaymentInfo, Bip353Error> {
+    let name = bip353_name(user, domain)?;
+
+    let rrs = parse_rr_stream(proof)
+        .map_err(|_| Bip353Error::DnssecError("Failed to parse DNSSEC proof".into()))?;
+
+    let verified = verify_rr_stream(&rrs, wallclock)
+        .map_err(|e| Bip353Error::DnssecError(format!("DNSSEC proof validation failed: {:?}", e)))?;
+
+    let txt = verified.resolved_txt_strings(&name)
+        .into_iter()
+        .find(|s| s.starts_with("bitcoin:"))
+        .ok_or_else(|| Bip353Error::InvalidRecord(format!("No BIP-353 payment instruction found in proof for {}", name)))?;
+
+    if let Some(expected_txt) = expected_txt {
+        if txt != expected_txt {
+            return Err(Bip353Error::DnssecError(format!(
+                "Proof for {} attests to a different payment instruction than expected", name,
+            )));
+        }
+    }
+
+    let mut info = PaymentInfo::from_verified_uri(txt)?;
+    info.dnssec_proof = Some(proof.to_vec());
+    Ok(info)
+}
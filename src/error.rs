@@ -28,6 +28,15 @@ pub enum Bip353Error {
     /// Network or I/O error
     #[error("Network error: {0}")]
     NetworkError(String),
+
+    /// The requested amount fell outside the bounds the recipient will accept
+    #[error("Amount out of range: must be between {min} and {max} sats")]
+    AmountOutOfRange {
+        /// The minimum amount the recipient will accept, in satoshis
+        min: u64,
+        /// The maximum amount the recipient will accept, in satoshis
+        max: u64,
+    },
 }
 
 impl From<bitcoin_payment_instructions::ParseError> for Bip353Error {
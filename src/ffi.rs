@@ -3,6 +3,7 @@
 //! These bindings provide a C API for integration with Bitcoin Core.
 
 use std::ffi::{c_char, CStr, CString};
+use std::os::raw::c_void;
 use std::ptr;
 use std::sync::Arc;
 use once_cell::sync::OnceCell;
@@ -70,6 +71,66 @@ pub extern "C" fn bip353_resolver_create_with_network(network_name: *const c_cha
     }
 }
 
+/// Create a new resolver whose network is auto-detected from a running
+/// bitcoind instance via `getblockchaininfo`, instead of being named by the
+/// caller up front
+#[cfg(feature = "bitcoind-rpc")]
+#[no_mangle]
+pub extern "C" fn bip353_resolver_create_from_bitcoind_rpc(
+    rpc_url: *const c_char,
+    rpc_user: *const c_char,
+    rpc_password: *const c_char,
+) -> *mut ResolverPtr {
+    if rpc_url.is_null() || rpc_user.is_null() || rpc_password.is_null() {
+        return ptr::null_mut();
+    }
+
+    let url_str = match unsafe { CStr::from_ptr(rpc_url) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    let user_str = match unsafe { CStr::from_ptr(rpc_user) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => return ptr::null_mut(),
+    };
+    let password_str = match unsafe { CStr::from_ptr(rpc_password) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let auth = bitcoincore_rpc::Auth::UserPass(user_str, password_str);
+
+    let config = match ResolverConfig::from_bitcoind_rpc(url_str, auth) {
+        Ok(config) => config,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match Bip353Resolver::with_config(config) {
+        Ok(resolver) => {
+            let resolver_ptr = Arc::new(resolver);
+            let ptr = Box::new(ResolverPtr(resolver_ptr));
+            Box::into_raw(ptr)
+        }
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Create a new resolver with default configuration and an in-memory
+/// resolution cache bounded to `capacity` entries
+#[no_mangle]
+pub extern "C" fn bip353_resolver_create_with_cache(capacity: usize) -> *mut ResolverPtr {
+    let config = ResolverConfig::default().with_cache(capacity);
+
+    match Bip353Resolver::with_config(config) {
+        Ok(resolver) => {
+            let resolver_ptr = Arc::new(resolver);
+            let ptr = Box::new(ResolverPtr(resolver_ptr));
+            Box::into_raw(ptr)
+        }
+        Err(_) => ptr::null_mut(),
+    }
+}
+
 /// Free a resolver
 #[no_mangle]
 pub extern "C" fn bip353_resolver_free(ptr: *mut ResolverPtr) {
@@ -85,16 +146,52 @@ pub extern "C" fn bip353_resolver_free(ptr: *mut ResolverPtr) {
 pub struct Bip353Result {
     /// Whether the resolution was successful
     success: bool,
-    
+
     /// The URI (BIP-21)
     uri: *mut c_char,
-    
+
     /// The payment type
     payment_type: *mut c_char,
-    
+
     /// Whether the payment is reusable
     is_reusable: bool,
-    
+
+    /// Whether `bolt11_*` fields below are populated
+    has_bolt11_details: bool,
+
+    /// The invoice amount in millisatoshis, or -1 for an "any amount" invoice
+    bolt11_amount_msat: i64,
+
+    /// The invoice payment hash, hex-encoded
+    bolt11_payment_hash: *mut c_char,
+
+    /// The payee's node id, hex-encoded (NULL if not recoverable from the invoice)
+    bolt11_payee_pubkey: *mut c_char,
+
+    /// Seconds after the invoice's timestamp that it remains valid for
+    bolt11_expiry_secs: u64,
+
+    /// Whether the invoice has already expired
+    bolt11_is_expired: bool,
+
+    /// Whether the `offer_*` fields below are populated
+    has_offer_details: bool,
+
+    /// The offer's fixed amount in millisatoshis, or -1 if the payer must choose
+    offer_amount_msat: i64,
+
+    /// Whether the payer must choose the amount when requesting an invoice
+    offer_amount_is_configurable: bool,
+
+    /// The offer's signing node id, hex-encoded (NULL if unset)
+    offer_signing_pubkey: *mut c_char,
+
+    /// The serialized DNSSEC proof chain, or NULL if one wasn't captured
+    dnssec_proof: *mut u8,
+
+    /// Length, in bytes, of `dnssec_proof`
+    dnssec_proof_len: usize,
+
     /// Error message (if any)
     error: *mut c_char,
 }
@@ -161,6 +258,98 @@ pub extern "C" fn bip353_resolve(
     create_result_ptr(result)
 }
 
+/// Resolve a human-readable Bitcoin address for a specific amount, for
+/// addresses that resolve to a configurable-amount method such as LNURL-Pay
+#[no_mangle]
+pub extern "C" fn bip353_resolve_for_amount(
+    ptr: *const ResolverPtr,
+    address: *const c_char,
+    amount_sat: u64,
+) -> *mut Bip353Result {
+    if ptr.is_null() || address.is_null() {
+        return ptr::null_mut();
+    }
+
+    let resolver_ptr = unsafe { &*ptr };
+    let resolver = &resolver_ptr.0;
+
+    let address_str = match unsafe { CStr::from_ptr(address) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let runtime = get_runtime();
+
+    let result = runtime.block_on(async {
+        resolver.resolve_for_amount(address_str, amount_sat).await
+    });
+
+    create_result_ptr(result)
+}
+
+/// Resolve a human-readable Bitcoin address into every payment method the
+/// record advertises, instead of the single preferred method `bip353_resolve_address`
+/// collapses them into
+///
+/// On success, `*out_len` is set to the number of results and the returned
+/// array (and every `Bip353Result` it points to) must be freed with
+/// `bip353_result_array_free`. Returns NULL (with `*out_len` set to 0) on error.
+#[no_mangle]
+pub extern "C" fn bip353_resolve_all(
+    ptr: *const ResolverPtr,
+    address: *const c_char,
+    out_len: *mut usize,
+) -> *mut *mut Bip353Result {
+    if ptr.is_null() || address.is_null() || out_len.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe { *out_len = 0 };
+
+    let resolver_ptr = unsafe { &*ptr };
+    let resolver = &resolver_ptr.0;
+
+    let address_str = match unsafe { CStr::from_ptr(address) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let runtime = get_runtime();
+
+    let results = match runtime.block_on(async {
+        resolver.resolve_address_all(address_str).await
+    }) {
+        Ok(results) => results,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let mut result_ptrs: Vec<*mut Bip353Result> = results.into_iter()
+        .map(|info| create_result_ptr(Ok(info)))
+        .collect();
+
+    let len = result_ptrs.len();
+    let array_ptr = result_ptrs.as_mut_ptr();
+    std::mem::forget(result_ptrs);
+
+    unsafe { *out_len = len };
+    array_ptr
+}
+
+/// Free an array of results returned by `bip353_resolve_all`
+#[no_mangle]
+pub extern "C" fn bip353_result_array_free(ptr: *mut *mut Bip353Result, len: usize) {
+    if ptr.is_null() {
+        return;
+    }
+
+    unsafe {
+        let results = Vec::from_raw_parts(ptr, len, len);
+        for result in results {
+            bip353_result_free(result);
+        }
+    }
+}
+
 fn create_result_ptr(result: Result<PaymentInfo, Bip353Error>) -> *mut Bip353Result {
     let result_ptr = Box::new(match result {
         Ok(info) => {
@@ -169,18 +358,87 @@ fn create_result_ptr(result: Result<PaymentInfo, Bip353Error>) -> *mut Bip353Res
                 Ok(s) => s,
                 Err(_) => return ptr::null_mut(),
             };
-            
+
             let type_str = info.payment_type.to_string();
             let type_cstring = match CString::new(type_str) {
                 Ok(s) => s,
                 Err(_) => return ptr::null_mut(),
             };
-            
+
+            let (has_bolt11_details, bolt11_amount_msat, bolt11_payment_hash, bolt11_payee_pubkey, bolt11_expiry_secs, bolt11_is_expired) =
+                match info.bolt11_details {
+                    Some(details) => {
+                        let payment_hash = match CString::new(details.payment_hash) {
+                            Ok(s) => s.into_raw(),
+                            Err(_) => return ptr::null_mut(),
+                        };
+                        let payee_pubkey = match details.payee_pubkey {
+                            Some(pk) => match CString::new(pk) {
+                                Ok(s) => s.into_raw(),
+                                Err(_) => return ptr::null_mut(),
+                            },
+                            None => ptr::null_mut(),
+                        };
+                        (
+                            true,
+                            details.amount_msat.map(|a| a as i64).unwrap_or(-1),
+                            payment_hash,
+                            payee_pubkey,
+                            details.expiry.as_secs(),
+                            details.is_expired(),
+                        )
+                    }
+                    None => (false, -1, ptr::null_mut(), ptr::null_mut(), 0, false),
+                };
+
+            let (has_offer_details, offer_amount_msat, offer_amount_is_configurable, offer_signing_pubkey) =
+                match info.offer_details {
+                    Some(details) => {
+                        let signing_pubkey = match details.signing_pubkey {
+                            Some(pk) => match CString::new(pk) {
+                                Ok(s) => s.into_raw(),
+                                Err(_) => return ptr::null_mut(),
+                            },
+                            None => ptr::null_mut(),
+                        };
+                        (
+                            true,
+                            details.amount_msat.map(|a| a as i64).unwrap_or(-1),
+                            details.amount_is_configurable,
+                            signing_pubkey,
+                        )
+                    }
+                    None => (false, -1, false, ptr::null_mut()),
+                };
+
+            let (dnssec_proof, dnssec_proof_len) = match info.dnssec_proof {
+                Some(proof) => {
+                    let mut boxed = proof.into_boxed_slice();
+                    let ptr = boxed.as_mut_ptr();
+                    let len = boxed.len();
+                    std::mem::forget(boxed);
+                    (ptr, len)
+                },
+                None => (ptr::null_mut(), 0),
+            };
+
             Bip353Result {
                 success: true,
                 uri: uri_cstring.into_raw(),
                 payment_type: type_cstring.into_raw(),
                 is_reusable: info.is_reusable,
+                has_bolt11_details,
+                bolt11_amount_msat,
+                bolt11_payment_hash,
+                bolt11_payee_pubkey,
+                bolt11_expiry_secs,
+                bolt11_is_expired,
+                has_offer_details,
+                offer_amount_msat,
+                offer_amount_is_configurable,
+                offer_signing_pubkey,
+                dnssec_proof,
+                dnssec_proof_len,
                 error: ptr::null_mut(),
             }
         }
@@ -190,17 +448,29 @@ fn create_result_ptr(result: Result<PaymentInfo, Bip353Error>) -> *mut Bip353Res
                 Ok(s) => s,
                 Err(_) => return ptr::null_mut(),
             };
-            
+
             Bip353Result {
                 success: false,
                 uri: ptr::null_mut(),
                 payment_type: ptr::null_mut(),
                 is_reusable: false,
+                has_bolt11_details: false,
+                bolt11_amount_msat: -1,
+                bolt11_payment_hash: ptr::null_mut(),
+                bolt11_payee_pubkey: ptr::null_mut(),
+                bolt11_expiry_secs: 0,
+                bolt11_is_expired: false,
+                has_offer_details: false,
+                offer_amount_msat: -1,
+                offer_amount_is_configurable: false,
+                offer_signing_pubkey: ptr::null_mut(),
+                dnssec_proof: ptr::null_mut(),
+                dnssec_proof_len: 0,
                 error: error_cstring.into_raw(),
             }
         }
     });
-    
+
     Box::into_raw(result_ptr)
 }
 
@@ -223,10 +493,152 @@ pub extern "C" fn bip353_result_free(ptr: *mut Bip353Result) {
             if !result.error.is_null() {
                 let _ = CString::from_raw(result.error);
             }
+
+            if !result.bolt11_payment_hash.is_null() {
+                let _ = CString::from_raw(result.bolt11_payment_hash);
+            }
+
+            if !result.bolt11_payee_pubkey.is_null() {
+                let _ = CString::from_raw(result.bolt11_payee_pubkey);
+            }
+
+            if !result.offer_signing_pubkey.is_null() {
+                let _ = CString::from_raw(result.offer_signing_pubkey);
+            }
+
+            if !result.dnssec_proof.is_null() {
+                let _ = Vec::from_raw_parts(result.dnssec_proof, result.dnssec_proof_len, result.dnssec_proof_len);
+            }
+        }
+    }
+}
+
+/// Resolve a human-readable Bitcoin address and capture the raw DNSSEC
+/// proof chain alongside it, for offline/air-gapped verification later
+#[no_mangle]
+pub extern "C" fn bip353_resolve_with_proof(
+    ptr: *const ResolverPtr,
+    address: *const c_char,
+) -> *mut Bip353Result {
+    if ptr.is_null() || address.is_null() {
+        return ptr::null_mut();
+    }
+
+    let resolver_ptr = unsafe { &*ptr };
+    let resolver = &resolver_ptr.0;
+
+    let address_str = match unsafe { CStr::from_ptr(address) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let runtime = get_runtime();
+
+    let result = runtime.block_on(async {
+        resolver.resolve_address_with_proof(address_str).await
+    }).map(|(info, _proof)| info);
+
+    create_result_ptr(result)
+}
+
+/// Callback invoked when an async resolution started by `bip353_resolve_async`
+/// completes. `result` follows the same ownership rules as every other
+/// `Bip353Result*` in this API: the callback must free it exactly once with
+/// `bip353_result_free`. `user_data` is passed through unchanged from the
+/// corresponding `bip353_resolve_async` call.
+pub type Bip353ResolveCallback = extern "C" fn(result: *mut Bip353Result, user_data: *mut c_void);
+
+/// `user_data` is an opaque caller-owned pointer; we never dereference it,
+/// only hand it back to `callback` on whichever runtime thread the
+/// resolution happens to finish on
+struct SendUserData(*mut c_void);
+unsafe impl Send for SendUserData {}
+
+/// Handle for a pending `bip353_resolve_async` call, see `bip353_cancel`
+pub struct Bip353CancelHandle(tokio::task::JoinHandle<()>);
+
+/// Resolve a human-readable Bitcoin address without blocking the calling
+/// thread: spawns the resolution on the shared Tokio runtime and returns
+/// immediately, invoking `callback` with the result once it's ready.
+///
+/// Returns a cancellation handle that must eventually be passed to
+/// `bip353_cancel`, whether or not the lookup is actually cancelled - this
+/// also releases the resources tracking the spawned task. Cancelling after
+/// the callback has already run is a harmless no-op.
+#[no_mangle]
+pub extern "C" fn bip353_resolve_async(
+    ptr: *const ResolverPtr,
+    address: *const c_char,
+    callback: Bip353ResolveCallback,
+    user_data: *mut c_void,
+) -> *mut Bip353CancelHandle {
+    if ptr.is_null() || address.is_null() {
+        return ptr::null_mut();
+    }
+
+    let resolver_ptr = unsafe { &*ptr };
+    let resolver = Arc::clone(&resolver_ptr.0);
+
+    let address_str = match unsafe { CStr::from_ptr(address) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let user_data = SendUserData(user_data);
+
+    let join_handle = get_runtime().spawn(async move {
+        let result = resolver.resolve_address(&address_str).await;
+        let result_ptr = create_result_ptr(result);
+        let SendUserData(user_data) = user_data;
+        callback(result_ptr, user_data);
+    });
+
+    Box::into_raw(Box::new(Bip353CancelHandle(join_handle)))
+}
+
+/// Cancel a pending `bip353_resolve_async` lookup, aborting it before its
+/// callback runs if it hasn't already, and free the handle either way
+#[no_mangle]
+pub extern "C" fn bip353_cancel(handle: *mut Bip353CancelHandle) {
+    if !handle.is_null() {
+        unsafe {
+            let handle = Box::from_raw(handle);
+            handle.0.abort();
         }
     }
 }
 
+/// Re-validate a previously captured DNSSEC proof with no network access,
+/// asserting it attests to the `bitcoin:` URI in `expected_txt`
+#[no_mangle]
+pub extern "C" fn bip353_verify_proof(
+    proof: *const u8,
+    proof_len: usize,
+    address: *const c_char,
+    expected_txt: *const c_char,
+) -> *mut Bip353Result {
+    if proof.is_null() || address.is_null() || expected_txt.is_null() {
+        return ptr::null_mut();
+    }
+
+    let address_str = match unsafe { CStr::from_ptr(address) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let expected_txt_str = match unsafe { CStr::from_ptr(expected_txt) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let proof_slice = unsafe { std::slice::from_raw_parts(proof, proof_len) };
+
+    let result = crate::parse_address(address_str)
+        .and_then(|(user, domain)| crate::verify_proof(proof_slice, &user, &domain, expected_txt_str));
+
+    create_result_ptr(result)
+}
+
 /// Parse a human-readable Bitcoin address into user and domain parts
 #[no_mangle]
 pub extern "C" fn bip353_parse_address(
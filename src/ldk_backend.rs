@@ -0,0 +1,172 @@
+//! Reference `PaymentBackend` implementation on top of LDK
+//!
+//! Enabled by the `ldk` feature. Rather than depending directly on LDK's
+//! `ChannelManager<...>` (which is generic over the host's chain monitor,
+//! signer, and logger), this talks to a small `LdkNodeHandle` trait so it
+//! can be wired up to whatever concrete instantiation the host already runs.
+
+use async_trait::async_trait;
+use std::time::Instant;
+
+use crate::payment::{PaymentBackend, PaymentError, PaymentOutcome, RetryStrategy};
+use crate::types::{Bolt11Details, OriginalInstructions, PaymentInfo, PaymentType};
+
+/// The subset of an LDK node's payment functionality `LdkPaymentBackend` needs
+pub trait LdkNodeHandle: Send + Sync {
+    /// Attempt to find a route to `invoice`'s payee for `amount_msat`,
+    /// returning the estimated routing fee in millisatoshis
+    fn find_route<'a>(
+        &'a self,
+        invoice: &'a lightning_invoice::Bolt11Invoice,
+        amount_msat: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<u64, String>> + Send + 'a>>;
+
+    /// Send `amount_msat` to the invoice, returning the preimage and fee paid
+    /// once the payment completes (success) or a final failure
+    fn pay_invoice<'a>(
+        &'a self,
+        invoice: &'a lightning_invoice::Bolt11Invoice,
+        amount_msat: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(String, u64), String>> + Send + 'a>>;
+
+    /// Attempt to find a route for an invoice request against `offer`'s
+    /// blinded paths for `amount_msat`, returning the estimated routing fee
+    /// in millisatoshis - used to pre-flight-probe a BOLT12 offer the same
+    /// way `find_route` does for a BOLT11 invoice
+    fn find_route_for_offer<'a>(
+        &'a self,
+        offer: &'a lightning::offers::offer::Offer,
+        amount_msat: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<u64, String>> + Send + 'a>>;
+
+    /// Request an invoice for `offer` (paying `amount_msat`, needed whenever
+    /// the offer itself doesn't fix an amount) and pay it once the invoice
+    /// comes back, returning the preimage and fee paid on success
+    fn pay_offer<'a>(
+        &'a self,
+        offer: &'a lightning::offers::offer::Offer,
+        amount_msat: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(String, u64), String>> + Send + 'a>>;
+}
+
+/// `PaymentBackend` implementation that pays BOLT11 invoices and BOLT12
+/// offers through an LDK node
+pub struct LdkPaymentBackend<H: LdkNodeHandle> {
+    node: H,
+}
+
+impl<H: LdkNodeHandle> LdkPaymentBackend<H> {
+    /// Wrap an `LdkNodeHandle` as a `PaymentBackend`
+    pub fn new(node: H) -> Self {
+        Self { node }
+    }
+
+    fn invoice_for(info: &PaymentInfo) -> Result<&lightning_invoice::Bolt11Invoice, PaymentError> {
+        match &info.original_instructions {
+            Some(OriginalInstructions::FixedAmount(fixed)) => {
+                fixed.methods().iter().find_map(|m| match m {
+                    bitcoin_payment_instructions::PaymentMethod::LightningBolt11(invoice) => Some(invoice),
+                    _ => None,
+                }).ok_or_else(|| PaymentError::UnsupportedMethod("no BOLT11 invoice in payment info".into()))
+            },
+            Some(OriginalInstructions::ConfigurableAmount(_)) => {
+                Err(PaymentError::UnsupportedMethod(
+                    "call resolve_for_amount first to get a concrete invoice".into(),
+                ))
+            },
+            None => Err(PaymentError::UnsupportedMethod(
+                "payment info has no original instructions to pay from".into(),
+            )),
+        }
+    }
+
+    fn offer_for(info: &PaymentInfo) -> Result<&lightning::offers::offer::Offer, PaymentError> {
+        match &info.original_instructions {
+            Some(OriginalInstructions::FixedAmount(fixed)) => {
+                fixed.methods().iter().find_map(|m| match m {
+                    bitcoin_payment_instructions::PaymentMethod::LightningBolt12(offer) => Some(offer),
+                    _ => None,
+                }).ok_or_else(|| PaymentError::UnsupportedMethod("no BOLT12 offer in payment info".into()))
+            },
+            Some(OriginalInstructions::ConfigurableAmount(_)) => {
+                Err(PaymentError::UnsupportedMethod(
+                    "call resolve_for_amount first to get a concrete offer".into(),
+                ))
+            },
+            None => Err(PaymentError::UnsupportedMethod(
+                "payment info has no original instructions to pay from".into(),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl<H: LdkNodeHandle> PaymentBackend for LdkPaymentBackend<H> {
+    async fn probe_route(&self, info: &PaymentInfo, amount_msat: u64) -> Result<u64, PaymentError> {
+        let fee_msat = match info.payment_type {
+            PaymentType::Lightning => {
+                let invoice = Self::invoice_for(info)?;
+                self.node.find_route(invoice, amount_msat).await
+            },
+            PaymentType::LightningOffer => {
+                let offer = Self::offer_for(info)?;
+                self.node.find_route_for_offer(offer, amount_msat).await
+            },
+            _ => return Err(PaymentError::UnsupportedMethod(format!("{}", info.payment_type))),
+        };
+
+        fee_msat.map_err(|e| {
+            if e.to_lowercase().contains("no route") {
+                PaymentError::RouteNotFound
+            } else {
+                PaymentError::ProbeFailed(e)
+            }
+        })
+    }
+
+    async fn send_payment(
+        &self,
+        info: &PaymentInfo,
+        amount_msat: u64,
+        retry: RetryStrategy,
+    ) -> Result<PaymentOutcome, PaymentError> {
+        // Pre-flight probe so we fail fast instead of leaving an HTLC in flight
+        self.probe_route(info, amount_msat).await?;
+
+        let payment_hash = match info.payment_type {
+            PaymentType::Lightning => Bolt11Details::from_invoice(Self::invoice_for(info)?).payment_hash,
+            // A BOLT12 invoice request's payment hash isn't known until the
+            // payee returns the invoice, so we can only report it afterwards
+            PaymentType::LightningOffer => String::new(),
+            _ => return Err(PaymentError::UnsupportedMethod(format!("{}", info.payment_type))),
+        };
+
+        let started = Instant::now();
+        let mut attempt = 0u32;
+
+        loop {
+            attempt += 1;
+
+            let attempt_result = match info.payment_type {
+                PaymentType::Lightning => self.node.pay_invoice(Self::invoice_for(info)?, amount_msat).await,
+                PaymentType::LightningOffer => self.node.pay_offer(Self::offer_for(info)?, amount_msat).await,
+                _ => unreachable!("checked above"),
+            };
+
+            match attempt_result {
+                Ok((payment_preimage, fee_msat)) => {
+                    return Ok(PaymentOutcome { payment_hash, payment_preimage, amount_msat, fee_msat });
+                },
+                Err(last_error) => {
+                    let keep_going = match &retry {
+                        RetryStrategy::Attempts(max) => attempt < *max,
+                        RetryStrategy::Timeout(timeout) => started.elapsed() < *timeout,
+                    };
+                    if !keep_going {
+                        return Err(PaymentError::RetriesExhausted { attempts: attempt, last_error });
+                    }
+                },
+            }
+        }
+    }
+}
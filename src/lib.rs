@@ -11,8 +11,14 @@ mod error;
 mod resolver;
 mod types;
 mod config;
-mod metrics;     
-mod monitoring;   
+mod metrics;
+mod monitoring;
+mod payment;
+mod dnssec;
+pub mod wallet;
+
+#[cfg(feature = "ldk")]
+pub mod ldk_backend;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
@@ -20,12 +26,21 @@ pub mod ffi;
 #[cfg(feature = "python")]
 pub mod python;
 
+#[cfg(feature = "server")]
+pub mod server;
+
 pub use error::Bip353Error;
 pub use resolver::{Bip353Resolver, ResolverType};
-pub use types::{PaymentInfo, PaymentType};
+pub use types::{PaymentInfo, PaymentType, Bolt11Details, RouteHintHop, OfferDetails};
 pub use config::ResolverConfig;
-pub use metrics::{Bip353Metrics, ResolutionStats, CacheStats};
-pub use monitoring::{ChainMonitor, ChainBackend, AddressUsedEvent};
+pub use metrics::{Bip353Metrics, ResolutionStats, CacheStats, LatencyPercentiles};
+pub use monitoring::{ChainMonitor, ChainBackend, AddressUsedEvent, ChainSource};
+#[cfg(feature = "http")]
+pub use monitoring::EsploraChainSource;
+#[cfg(feature = "bitcoind-rpc")]
+pub use monitoring::BitcoindChainSource;
+pub use payment::{PaymentBackend, PaymentOutcome, PaymentError, RetryStrategy};
+pub use dnssec::verify_proof;
 
 /// BIP-353 Bitcoin address parsing utility
 ///
@@ -1,8 +1,92 @@
 //! This should show metrics collection for basic operations
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
 use std::time::Duration;
 
+/// Upper bounds (in milliseconds) of the fixed-boundary resolution-latency
+/// histogram, cumulative per Prometheus/OpenMetrics convention - each bucket
+/// counts every sample at or below its own bound, not just samples strictly
+/// between it and the previous bound
+const LATENCY_BUCKETS_MS: [u64; 8] = [10, 25, 50, 100, 250, 500, 1000, 2500];
+
+/// Per-domain resolution counters and latency histogram
+#[derive(Debug)]
+struct DomainMetrics {
+    resolutions_success: AtomicU64,
+    resolutions_failed: AtomicU64,
+    /// One counter per `LATENCY_BUCKETS_MS` entry, plus a trailing +Inf bucket
+    latency_buckets: Vec<AtomicU64>,
+    latency_sum_ms: AtomicU64,
+    latency_count: AtomicU64,
+}
+
+impl Default for DomainMetrics {
+    fn default() -> Self {
+        Self {
+            resolutions_success: AtomicU64::new(0),
+            resolutions_failed: AtomicU64::new(0),
+            latency_buckets: (0..=LATENCY_BUCKETS_MS.len()).map(|_| AtomicU64::new(0)).collect(),
+            latency_sum_ms: AtomicU64::new(0),
+            latency_count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl DomainMetrics {
+    fn record_success(&self, duration_ms: f64) {
+        self.resolutions_success.fetch_add(1, Ordering::Relaxed);
+        self.latency_sum_ms.fetch_add(duration_ms.round() as u64, Ordering::Relaxed);
+        self.latency_count.fetch_add(1, Ordering::Relaxed);
+
+        for (i, &bound_ms) in LATENCY_BUCKETS_MS.iter().enumerate() {
+            if duration_ms <= bound_ms as f64 {
+                self.latency_buckets[i].fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        // The +Inf bucket always catches every sample, however slow
+        self.latency_buckets[LATENCY_BUCKETS_MS.len()].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Estimated p50/p90/p99 resolution latency, derived from the fixed-boundary
+/// histogram - see [`Bip353Metrics::get_latency_percentiles`]
+#[derive(Debug, Clone)]
+pub struct LatencyPercentiles {
+    /// Upper bound of the bucket the 50th-percentile sample fell into, in
+    /// milliseconds, or `None` if there are no samples (or the
+    /// 50th-percentile sample exceeded every bucket's bound)
+    pub p50_ms: Option<u64>,
+    pub p90_ms: Option<u64>,
+    pub p99_ms: Option<u64>,
+    /// Number of samples the estimate is based on
+    pub sample_count: u64,
+}
+
+/// Smallest bucket bound whose cumulative count covers `quantile` of `total`
+/// samples, or `None` if `total` is zero or the `quantile` target is only
+/// reached in the +Inf overflow bucket
+///
+/// `bucket_counts` is already cumulative (each entry counts every sample at
+/// or below its own bound), so this only needs to find the first bucket
+/// whose own count clears the target - it must not be re-accumulated.
+fn percentile_from_buckets(bucket_counts: &[u64], total: u64, quantile: f64) -> Option<u64> {
+    if total == 0 {
+        return None;
+    }
+
+    let target = (quantile * total as f64).ceil() as u64;
+
+    for (i, &count) in bucket_counts.iter().enumerate() {
+        if count >= target {
+            return LATENCY_BUCKETS_MS.get(i).copied();
+        }
+    }
+
+    None
+}
+
 #[derive(Debug, Default)]
 pub struct Bip353Metrics {
     // Counters
@@ -11,7 +95,11 @@ pub struct Bip353Metrics {
     resolutions_failed: AtomicU64,
     cache_hits: AtomicU64,
     cache_misses: AtomicU64,
+    cache_evictions: AtomicU64,
     address_reuse_detected: AtomicU64,
+    /// Per-domain counters and latency histograms, so slow or failing
+    /// authoritative servers can be told apart from a globally slow path
+    domains: RwLock<HashMap<String, DomainMetrics>>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +116,7 @@ pub struct CacheStats {
     pub misses: u64,
     pub total: u64,
     pub hit_rate: f64,
+    pub evictions: u64,
 }
 
 impl Bip353Metrics {
@@ -35,16 +124,35 @@ impl Bip353Metrics {
         Self::default()
     }
     
-    /// Record a successful resolution
-    pub async fn record_resolution_success(&self, _domain: &str, _duration: Duration) {
+    /// Record a successful resolution, including its wall-clock duration in
+    /// the per-domain latency histogram
+    pub async fn record_resolution_success(&self, domain: &str, duration: Duration) {
         self.resolutions_total.fetch_add(1, Ordering::Relaxed);
         self.resolutions_success.fetch_add(1, Ordering::Relaxed);
+
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        self.with_domain_metrics(domain, |metrics| metrics.record_success(duration_ms));
     }
-    
+
     /// Record a failed resolution
-    pub async fn record_resolution_failure(&self, _domain: &str, _error_type: &str) {
+    pub async fn record_resolution_failure(&self, domain: &str, _error_type: &str) {
         self.resolutions_total.fetch_add(1, Ordering::Relaxed);
         self.resolutions_failed.fetch_add(1, Ordering::Relaxed);
+
+        self.with_domain_metrics(domain, |metrics| {
+            metrics.resolutions_failed.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Run `f` against `domain`'s entry in the per-domain metrics map,
+    /// creating it on first use
+    fn with_domain_metrics(&self, domain: &str, f: impl FnOnce(&DomainMetrics)) {
+        if let Some(metrics) = self.domains.read().unwrap().get(domain) {
+            return f(metrics);
+        }
+
+        let mut domains = self.domains.write().unwrap();
+        f(domains.entry(domain.to_string()).or_default());
     }
     
     /// Record cache hit
@@ -56,7 +164,13 @@ impl Bip353Metrics {
     pub fn record_cache_miss(&self) {
         self.cache_misses.fetch_add(1, Ordering::Relaxed);
     }
-    
+
+    /// Record a cache eviction, i.e. an entry that was found but had outlived
+    /// its TTL, or one dropped to keep the cache within its capacity
+    pub fn record_cache_eviction(&self) {
+        self.cache_evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Record address reuse detection
     pub fn record_address_reuse(&self) {
         self.address_reuse_detected.fetch_add(1, Ordering::Relaxed);
@@ -87,6 +201,98 @@ impl Bip353Metrics {
             misses,
             total,
             hit_rate: if total > 0 { (hits as f64) / (total as f64) } else { 0.0 },
+            evictions: self.cache_evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Estimate p50/p90/p99 resolution latency from the fixed-boundary
+    /// histogram, either for a single `domain` or, when `None`, aggregated
+    /// across every domain seen so far
+    pub fn get_latency_percentiles(&self, domain: Option<&str>) -> LatencyPercentiles {
+        let domains = self.domains.read().unwrap();
+        let mut bucket_counts = vec![0u64; LATENCY_BUCKETS_MS.len() + 1];
+        let mut total = 0u64;
+
+        let mut accumulate = |metrics: &DomainMetrics| {
+            for (i, bucket) in metrics.latency_buckets.iter().enumerate() {
+                bucket_counts[i] += bucket.load(Ordering::Relaxed);
+            }
+            total += metrics.latency_count.load(Ordering::Relaxed);
+        };
+
+        match domain {
+            Some(domain) => {
+                if let Some(metrics) = domains.get(domain) {
+                    accumulate(metrics);
+                }
+            },
+            None => domains.values().for_each(accumulate),
         }
+
+        LatencyPercentiles {
+            p50_ms: percentile_from_buckets(&bucket_counts, total, 0.50),
+            p90_ms: percentile_from_buckets(&bucket_counts, total, 0.90),
+            p99_ms: percentile_from_buckets(&bucket_counts, total, 0.99),
+            sample_count: total,
+        }
+    }
+
+    /// Render every counter and the per-domain latency histogram in
+    /// Prometheus/OpenMetrics text exposition format, suitable for a scrape
+    /// endpoint
+    pub fn encode_openmetrics(&self) -> String {
+        let mut out = String::new();
+
+        let resolutions = self.get_resolution_stats();
+        out.push_str("# TYPE bip353_resolutions_total counter\n");
+        out.push_str(&format!("bip353_resolutions_total {}\n", resolutions.total));
+        out.push_str("# TYPE bip353_resolutions_success_total counter\n");
+        out.push_str(&format!("bip353_resolutions_success_total {}\n", resolutions.success));
+        out.push_str("# TYPE bip353_resolutions_failed_total counter\n");
+        out.push_str(&format!("bip353_resolutions_failed_total {}\n", resolutions.failed));
+
+        let cache = self.get_cache_stats();
+        out.push_str("# TYPE bip353_cache_hits_total counter\n");
+        out.push_str(&format!("bip353_cache_hits_total {}\n", cache.hits));
+        out.push_str("# TYPE bip353_cache_misses_total counter\n");
+        out.push_str(&format!("bip353_cache_misses_total {}\n", cache.misses));
+        out.push_str("# TYPE bip353_cache_evictions_total counter\n");
+        out.push_str(&format!("bip353_cache_evictions_total {}\n", cache.evictions));
+
+        out.push_str("# TYPE bip353_address_reuse_detected_total counter\n");
+        out.push_str(&format!(
+            "bip353_address_reuse_detected_total {}\n",
+            self.address_reuse_detected.load(Ordering::Relaxed),
+        ));
+
+        out.push_str("# TYPE bip353_resolution_duration_milliseconds histogram\n");
+        let domains = self.domains.read().unwrap();
+        for (domain, metrics) in domains.iter() {
+            // `latency_buckets` is already cumulative (each entry counts
+            // every sample at or below its own bound), matching what
+            // Prometheus/OpenMetrics expects for `_bucket{le=...}` - emit
+            // the counts as-is rather than summing them again.
+            for (i, &bound_ms) in LATENCY_BUCKETS_MS.iter().enumerate() {
+                let count = metrics.latency_buckets[i].load(Ordering::Relaxed);
+                out.push_str(&format!(
+                    "bip353_resolution_duration_milliseconds_bucket{{domain=\"{domain}\",le=\"{bound_ms}\"}} {count}\n",
+                ));
+            }
+            let inf_count = metrics.latency_buckets[LATENCY_BUCKETS_MS.len()].load(Ordering::Relaxed);
+            out.push_str(&format!(
+                "bip353_resolution_duration_milliseconds_bucket{{domain=\"{domain}\",le=\"+Inf\"}} {inf_count}\n",
+            ));
+            out.push_str(&format!(
+                "bip353_resolution_duration_milliseconds_sum{{domain=\"{domain}\"}} {}\n",
+                metrics.latency_sum_ms.load(Ordering::Relaxed),
+            ));
+            out.push_str(&format!(
+                "bip353_resolution_duration_milliseconds_count{{domain=\"{domain}\"}} {}\n",
+                metrics.latency_count.load(Ordering::Relaxed),
+            ));
+        }
+        out.push_str("# EOF\n");
+
+        out
     }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 //! This is useful in monitoring backend events
 
-use bitcoin::Address;
+use bitcoin::{Address, Network, Script, Txid};
 use async_trait::async_trait;
 
 /// (lets users plug in their own)
@@ -8,11 +8,108 @@ use async_trait::async_trait;
 pub trait ChainBackend: Send + Sync {
     /// Check if an address has been used
     async fn is_address_used(&self, address: &Address) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
-    
+
     /// Get transaction history for an address
     async fn get_address_history(&self, address: &Address) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>>;
 }
 
+/// Pluggable source of on-chain data for detecting address reuse
+///
+/// Unlike `ChainBackend` above (a stub kept around for source compatibility),
+/// this is what `Bip353Resolver::with_chain_source` actually wires up: a
+/// minimal, implementation-agnostic way to ask "has this scriptPubKey ever
+/// been paid?" so `check_basic_warnings` can populate `AddressWarning::AddressReused`.
+#[async_trait]
+pub trait ChainSource: Send + Sync {
+    /// Confirmed transactions that pay `spk`, most recent last
+    async fn address_history(&self, spk: &Script) -> Result<Vec<Txid>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// `ChainSource` backed by an Esplora-compatible HTTP API (blockstream.info,
+/// mempool.space, or a self-hosted instance)
+#[cfg(feature = "http")]
+pub struct EsploraChainSource {
+    base_url: String,
+    network: Network,
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "http")]
+impl EsploraChainSource {
+    /// `base_url` is the Esplora instance's API root, e.g. "https://blockstream.info/api"
+    pub fn new(base_url: impl Into<String>, network: Network) -> Self {
+        Self { base_url: base_url.into(), network, client: reqwest::Client::new() }
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait]
+impl ChainSource for EsploraChainSource {
+    async fn address_history(&self, spk: &Script) -> Result<Vec<Txid>, Box<dyn std::error::Error + Send + Sync>> {
+        use std::str::FromStr;
+
+        let address = Address::from_script(spk, self.network)?;
+        let url = format!("{}/address/{}/txs", self.base_url.trim_end_matches('/'), address);
+
+        let txs: Vec<serde_json::Value> = self.client.get(&url).send().await?.json().await?;
+
+        // Esplora returns transactions newest-first; reverse so the trait's
+        // "most recent last" contract holds
+        Ok(txs.into_iter()
+            .filter(|tx| tx["status"]["confirmed"].as_bool().unwrap_or(false))
+            .filter_map(|tx| tx["txid"].as_str().and_then(|txid| Txid::from_str(txid).ok()))
+            .rev()
+            .collect())
+    }
+}
+
+/// `ChainSource` backed by a `bitcoind` node's own JSON-RPC interface
+///
+/// Uses `scantxoutset` against the node's current UTXO set, so a `spk` that
+/// was paid and has since been spent elsewhere won't show up here; this is a
+/// best-effort check, not an authoritative one.
+#[cfg(feature = "bitcoind-rpc")]
+pub struct BitcoindChainSource {
+    client: bitcoincore_rpc::Client,
+}
+
+#[cfg(feature = "bitcoind-rpc")]
+impl BitcoindChainSource {
+    pub fn new(url: &str, auth: bitcoincore_rpc::Auth) -> Result<Self, bitcoincore_rpc::Error> {
+        Ok(Self { client: bitcoincore_rpc::Client::new(url, auth)? })
+    }
+}
+
+#[cfg(feature = "bitcoind-rpc")]
+#[async_trait]
+impl ChainSource for BitcoindChainSource {
+    async fn address_history(&self, spk: &Script) -> Result<Vec<Txid>, Box<dyn std::error::Error + Send + Sync>> {
+        use std::str::FromStr;
+        use bitcoincore_rpc::RpcApi;
+
+        let descriptor = format!("raw({})", spk.to_hex_string());
+
+        // `call` blocks the calling thread on I/O, so run it on a blocking
+        // thread instead of tying up the async executor
+        let client = &self.client;
+        let result: serde_json::Value = tokio::task::block_in_place(|| {
+            client.call("scantxoutset", &[serde_json::json!("start"), serde_json::json!([descriptor])])
+        })?;
+
+        let mut unspents: Vec<(u64, Txid)> = result["unspents"].as_array().cloned().unwrap_or_default()
+            .into_iter()
+            .filter_map(|unspent| {
+                let txid = unspent["txid"].as_str().and_then(|txid| Txid::from_str(txid).ok())?;
+                Some((unspent["height"].as_u64().unwrap_or(0), txid))
+            })
+            .collect();
+
+        // Sort by confirmation height so the trait's "most recent last" contract holds
+        unspents.sort_by_key(|(height, _)| *height);
+        Ok(unspents.into_iter().map(|(_, txid)| txid).collect())
+    }
+}
+
 /// Simple event when an address is detected as used
 #[derive(Debug, Clone)]
 pub struct AddressUsedEvent {
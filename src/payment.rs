@@ -0,0 +1,89 @@
+//! Pluggable payment execution backends
+//!
+//! `ChainBackend` (in `monitoring.rs`) lets callers observe the chain;
+//! `PaymentBackend` is its counterpart for actually spending funds. Resolving
+//! a BIP-353 address only gets a caller as far as a `PaymentInfo` - plugging
+//! in a `PaymentBackend` turns that into an end-to-end "paste a ₿user@domain
+//! and pay" flow.
+
+use async_trait::async_trait;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::types::PaymentInfo;
+
+/// Errors that can occur while probing or sending a payment
+#[derive(Error, Debug)]
+pub enum PaymentError {
+    /// `info` isn't a payment method this backend knows how to pay
+    #[error("Unsupported payment method: {0}")]
+    UnsupportedMethod(String),
+
+    /// No viable route to the payee could be found during pre-flight probing
+    #[error("No route found to the payee")]
+    RouteNotFound,
+
+    /// Pre-flight route probing failed for a reason other than "no route"
+    #[error("Route probing failed: {0}")]
+    ProbeFailed(String),
+
+    /// The configured retry strategy was exhausted without success
+    #[error("Payment retries exhausted after {attempts} attempt(s): {last_error}")]
+    RetriesExhausted { attempts: u32, last_error: String },
+
+    /// Error surfaced directly from the underlying payment backend
+    #[error("Payment backend error: {0}")]
+    BackendError(String),
+}
+
+/// How many times, or for how long, to retry a failed payment attempt
+#[derive(Debug, Clone)]
+pub enum RetryStrategy {
+    /// Give up after this many attempts (including the first)
+    Attempts(u32),
+
+    /// Keep retrying until this much wall-clock time has elapsed
+    Timeout(Duration),
+}
+
+impl Default for RetryStrategy {
+    fn default() -> Self {
+        RetryStrategy::Attempts(3)
+    }
+}
+
+/// The result of a successfully completed payment
+#[derive(Debug, Clone)]
+pub struct PaymentOutcome {
+    /// The payment hash, hex-encoded
+    pub payment_hash: String,
+
+    /// The preimage that proves payment, hex-encoded, once it settles
+    pub payment_preimage: String,
+
+    /// The amount actually sent to the payee, in millisatoshis
+    pub amount_msat: u64,
+
+    /// The total routing fee paid, in millisatoshis
+    pub fee_msat: u64,
+}
+
+/// A pluggable backend capable of actually paying a resolved `PaymentInfo`
+///
+/// This is intentionally minimal so callers can plug in whatever they
+/// already use to move funds (an LDK node, CLN/LND over their RPCs, a
+/// custodial API, ...); see the `ldk` feature for a reference implementation.
+#[async_trait]
+pub trait PaymentBackend: Send + Sync {
+    /// Pre-flight-probe a route to the payee without committing any funds,
+    /// returning the estimated routing fee in millisatoshis if one was found
+    async fn probe_route(&self, info: &PaymentInfo, amount_msat: u64) -> Result<u64, PaymentError>;
+
+    /// Pay the resolved payment info, retrying according to `retry`
+    async fn send_payment(
+        &self,
+        info: &PaymentInfo,
+        amount_msat: u64,
+        retry: RetryStrategy,
+    ) -> Result<PaymentOutcome, PaymentError>;
+}
@@ -18,7 +18,7 @@ use crate::{
 /// Convert a BIP-353 error to a Python exception
 fn to_py_err(err: Bip353Error) -> PyErr {
     match err {
-        Bip353Error::InvalidAddress(_) => PyValueError::new_err(err.to_string()),
+        Bip353Error::InvalidAddress(_) | Bip353Error::AmountOutOfRange { .. } => PyValueError::new_err(err.to_string()),
         _ => PyRuntimeError::new_err(err.to_string()),
     }
 }
@@ -75,6 +75,34 @@ impl PyResolver {
         Ok(PyPaymentInfo { instruction })
     }
     
+    /// Resolve a human-readable Bitcoin address for a specific amount, for
+    /// addresses that resolve to a configurable-amount method such as LNURL-Pay
+    fn resolve_for_amount(&self, address: &str, amount_sat: u64) -> PyResult<PyPaymentInfo> {
+        let instruction = self.rt.block_on(self.resolver.resolve_for_amount(address, amount_sat))
+            .map_err(to_py_err)?;
+
+        Ok(PyPaymentInfo { instruction })
+    }
+
+    /// Resolve a user@domain combination and capture the raw DNSSEC proof
+    /// chain alongside it, for offline/air-gapped verification later
+    fn resolve_with_proof(&self, user: &str, domain: &str) -> PyResult<PyPaymentInfo> {
+        let (instruction, _proof) = self.rt.block_on(self.resolver.resolve_with_proof(user, domain))
+            .map_err(to_py_err)?;
+
+        Ok(PyPaymentInfo { instruction })
+    }
+
+    /// Resolve a human-readable Bitcoin address into every payment method
+    /// the record advertises, instead of the single preferred method
+    /// `resolve_address` collapses them into
+    fn resolve_all(&self, address: &str) -> PyResult<Vec<PyPaymentInfo>> {
+        let instructions = self.rt.block_on(self.resolver.resolve_address_all(address))
+            .map_err(to_py_err)?;
+
+        Ok(instructions.into_iter().map(|instruction| PyPaymentInfo { instruction }).collect())
+    }
+
     /// Parse a human-readable Bitcoin address
     fn parse_address(&self, address: &str) -> PyResult<(String, String)> {
         crate::parse_address(address).map_err(to_py_err)
@@ -116,13 +144,103 @@ impl PyPaymentInfo {
     #[getter]
     fn parameters(&self, py: Python) -> PyObject {
         let dict = PyDict::new(py);
-        
+
         for (key, value) in &self.instruction.parameters {
             dict.set_item(key, value).unwrap();
         }
-        
+
         dict.into()
     }
+
+    /// The decoded BOLT11 invoice amount in millisatoshis, or `None` for an
+    /// "any amount" invoice, or `None` if this isn't a Lightning payment
+    #[getter]
+    fn bolt11_amount_msat(&self) -> Option<u64> {
+        self.instruction.bolt11_details.as_ref()?.amount_msat
+    }
+
+    /// The decoded BOLT11 payment hash, hex-encoded
+    #[getter]
+    fn bolt11_payment_hash(&self) -> Option<String> {
+        self.instruction.bolt11_details.as_ref().map(|d| d.payment_hash.clone())
+    }
+
+    /// The decoded BOLT11 invoice description, if it carries one directly
+    #[getter]
+    fn bolt11_description(&self) -> Option<String> {
+        self.instruction.bolt11_details.as_ref()?.description.clone()
+    }
+
+    /// Seconds after the BOLT11 invoice's timestamp that it remains valid for
+    #[getter]
+    fn bolt11_expiry_secs(&self) -> Option<u64> {
+        self.instruction.bolt11_details.as_ref().map(|d| d.expiry.as_secs())
+    }
+
+    /// Whether the decoded BOLT11 invoice has already expired
+    #[getter]
+    fn bolt11_is_expired(&self) -> Option<bool> {
+        self.instruction.bolt11_details.as_ref().map(|d| d.is_expired())
+    }
+
+    /// The BOLT11 payee node id, hex-encoded, when recoverable from the invoice
+    #[getter]
+    fn bolt11_payee_pubkey(&self) -> Option<String> {
+        self.instruction.bolt11_details.as_ref()?.payee_pubkey.clone()
+    }
+
+    /// The decoded BOLT12 offer's fixed amount in millisatoshis, or `None` if
+    /// the payer must choose the amount (see `offer_amount_is_configurable`)
+    #[getter]
+    fn offer_amount_msat(&self) -> Option<u64> {
+        self.instruction.offer_details.as_ref()?.amount_msat
+    }
+
+    /// Whether the payer must choose an amount when requesting an invoice
+    /// from the decoded BOLT12 offer
+    #[getter]
+    fn offer_amount_is_configurable(&self) -> Option<bool> {
+        self.instruction.offer_details.as_ref().map(|d| d.amount_is_configurable)
+    }
+
+    /// The decoded BOLT12 offer's description
+    #[getter]
+    fn offer_description(&self) -> Option<String> {
+        self.instruction.offer_details.as_ref()?.description.clone()
+    }
+
+    /// The decoded BOLT12 offer's issuer, if set
+    #[getter]
+    fn offer_issuer(&self) -> Option<String> {
+        self.instruction.offer_details.as_ref()?.issuer.clone()
+    }
+
+    /// The maximum quantity of the item the decoded BOLT12 offer allows in
+    /// one invoice, or `None` if unbounded
+    #[getter]
+    fn offer_quantity_max(&self) -> Option<u64> {
+        self.instruction.offer_details.as_ref()?.quantity_max
+    }
+
+    /// The decoded BOLT12 offer's signing node id, hex-encoded
+    #[getter]
+    fn offer_signing_pubkey(&self) -> Option<String> {
+        self.instruction.offer_details.as_ref()?.signing_pubkey.clone()
+    }
+
+    /// The captured DNSSEC proof chain, if one was requested at resolution time
+    #[getter]
+    fn dnssec_proof<'p>(&self, py: Python<'p>) -> Option<&'p pyo3::types::PyBytes> {
+        self.instruction.dnssec_proof.as_ref().map(|proof| pyo3::types::PyBytes::new(py, proof))
+    }
+}
+
+/// Re-validate a previously captured DNSSEC proof with no network access,
+/// asserting it attests to the `bitcoin:` URI in `expected_txt`
+#[pyfunction]
+fn verify_proof(proof: &[u8], user: &str, domain: &str, expected_txt: &str) -> PyResult<PyPaymentInfo> {
+    let instruction = crate::verify_proof(proof, user, domain, expected_txt).map_err(to_py_err)?;
+    Ok(PyPaymentInfo { instruction })
 }
 
 /// Python module
@@ -130,6 +248,7 @@ impl PyPaymentInfo {
 pub fn bip353(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyResolver>()?;
     m.add_class::<PyPaymentInfo>()?;
-    
+    m.add_function(wrap_pyfunction!(verify_proof, m)?)?;
+
     Ok(())
 }
\ No newline at end of file
@@ -11,12 +11,15 @@ use bitcoin_payment_instructions::http_resolver::HTTPHrnResolver;
 use crate::{
     Bip353Error,
     config::ResolverConfig,
-    types::PaymentInfo,
+    types::{PaymentInfo, PaymentType},
     parse_address,
     metrics::Bip353Metrics,
+    monitoring::ChainSource,
+    payment::PaymentBackend,
 };
 
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use std::time::{SystemTime, Duration};
@@ -38,6 +41,20 @@ pub struct SafePaymentInfo {
     pub payment_info: PaymentInfo,
     pub warnings: Vec<AddressWarning>,
     pub last_checked: SystemTime,
+    /// Outcome of a Lightning pre-flight route probe, see
+    /// [`Bip353Resolver::resolve_with_safety_checks_and_probe`]; `None`
+    /// unless probing was requested and the resolved method was Lightning
+    pub route_probe: Option<RouteProbeResult>,
+}
+
+/// Outcome of a pre-flight Lightning route probe, see
+/// [`Bip353Resolver::resolve_with_safety_checks_and_probe`]
+#[derive(Debug, Clone)]
+pub struct RouteProbeResult {
+    /// Whether a route was found for the probed amount
+    pub feasible: bool,
+    /// Estimated routing fee in millisatoshis, if a route was found
+    pub fee_msat: Option<u64>,
 }
 
 /// Address usage warning
@@ -49,53 +66,218 @@ pub enum AddressWarning {
     StaleRecord { age: Duration },
     /// DNSSEC validation issues
     DnssecWarning { message: String },
+    /// A Lightning pre-flight route probe failed to find a viable route;
+    /// non-fatal so a wallet can fall back to another method in the same
+    /// BIP-21 URI (e.g. the on-chain address) instead of erroring outright
+    Unroutable { reason: String },
 }
 
+/// TTL a cache entry gets when the resolver has no better signal (e.g. the
+/// record's own DNS TTL) to derive one from
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
 /// Simple address cache with TTL
 #[derive(Debug)]
 struct AddressCache {
     entries: Arc<RwLock<HashMap<String, CacheEntry>>>,
     default_ttl: Duration,
+    /// Maximum number of entries to hold before evicting the
+    /// least-recently-used one; `None` means unbounded
+    capacity: Option<usize>,
+    /// Upper bound every inserted entry's TTL is capped to, see
+    /// `ResolverConfig::with_max_cache_ttl`
+    max_ttl: Option<Duration>,
+    /// One lock per in-flight resolution, so concurrent lookups for the same
+    /// `hrn` on a cache miss wait for the first resolution instead of each
+    /// independently stampeding the DNS resolver
+    in_flight: Arc<RwLock<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
 }
 
 #[derive(Debug, Clone)]
 struct CacheEntry {
     payment_info: PaymentInfo,
     cached_at: SystemTime,
+    /// Updated on every cache hit (see `AddressCache::touch`), independent
+    /// of `cached_at`, so capacity eviction in `insert_with_ttl` can pick
+    /// the least-recently-*used* entry rather than the least-recently-*inserted*
+    /// one
+    last_accessed: SystemTime,
     ttl: Duration,
 }
 
+/// Result of a cache lookup, distinguishing a hit from an entry that was
+/// present but has outlived its TTL (and was evicted as a result). A hit
+/// carries the entry's age and TTL alongside the `PaymentInfo` so the caller
+/// can decide whether the record is old enough to warrant `StaleRecord`.
+enum CacheLookup {
+    Hit(PaymentInfo, Duration, Duration),
+    Expired,
+    Miss,
+}
+
 impl AddressCache {
     fn new(default_ttl: Duration) -> Self {
+        Self::with_capacity(default_ttl, None)
+    }
+
+    fn with_capacity(default_ttl: Duration, capacity: Option<usize>) -> Self {
+        Self::with_capacity_and_max_ttl(default_ttl, capacity, None)
+    }
+
+    fn with_capacity_and_max_ttl(default_ttl: Duration, capacity: Option<usize>, max_ttl: Option<Duration>) -> Self {
         Self {
             entries: Arc::new(RwLock::new(HashMap::new())),
             default_ttl,
+            capacity,
+            max_ttl,
+            in_flight: Arc::new(RwLock::new(HashMap::new())),
         }
     }
-    
-    async fn get(&self, hrn: &str) -> Option<PaymentInfo> {
-        let entries = self.entries.read().await;
-        if let Some(entry) = entries.get(hrn) {
-            if entry.cached_at.elapsed().unwrap_or(Duration::MAX) < entry.ttl {
-                return Some(entry.payment_info.clone());
+
+    async fn get(&self, hrn: &str) -> CacheLookup {
+        enum Lookup {
+            Hit(PaymentInfo, Duration, Duration),
+            Stale,
+            Miss,
+        }
+
+        let lookup = {
+            let entries = self.entries.read().await;
+            match entries.get(hrn) {
+                Some(entry) if Self::is_live(entry) => {
+                    let age = entry.cached_at.elapsed().unwrap_or(Duration::ZERO);
+                    Lookup::Hit(entry.payment_info.clone(), age, entry.ttl)
+                },
+                Some(_) => Lookup::Stale, // present but stale; fall through to evict it below
+                None => Lookup::Miss,
             }
+        };
+
+        match lookup {
+            Lookup::Hit(info, age, ttl) => {
+                // A hit counts as a use, so it should protect this entry
+                // from LRU eviction ahead of entries nobody's asked for in a while
+                self.touch(hrn).await;
+                CacheLookup::Hit(info, age, ttl)
+            },
+            Lookup::Stale => {
+                // The entry was stale: evict it so it doesn't linger forever
+                let mut entries = self.entries.write().await;
+                entries.remove(hrn);
+                CacheLookup::Expired
+            },
+            Lookup::Miss => CacheLookup::Miss,
         }
-        None
     }
-    
-    async fn insert(&self, hrn: String, payment_info: PaymentInfo) {
+
+    /// Bump `hrn`'s last-accessed timestamp on a cache hit; see `CacheEntry::last_accessed`
+    async fn touch(&self, hrn: &str) {
+        if let Some(entry) = self.entries.write().await.get_mut(hrn) {
+            entry.last_accessed = SystemTime::now();
+        }
+    }
+
+    /// Whether a cached entry is still servable: within its TTL, and - for a
+    /// one-shot BOLT11 invoice - not past its own expiry, so a wallet never
+    /// gets handed an invoice that's individually expired even though the
+    /// cache entry's TTL hasn't elapsed yet
+    fn is_live(entry: &CacheEntry) -> bool {
+        let within_ttl = entry.cached_at.elapsed().unwrap_or(Duration::MAX) < entry.ttl;
+        let invoice_expired = entry.payment_info.bolt11_details.as_ref()
+            .map(|d| d.is_expired())
+            .unwrap_or(false);
+
+        within_ttl && !invoice_expired
+    }
+
+    /// Acquire (creating if needed) the per-`hrn` lock used to dedupe
+    /// concurrent cache misses into a single in-flight resolution
+    async fn lock_for(&self, hrn: &str) -> Arc<tokio::sync::Mutex<()>> {
+        if let Some(lock) = self.in_flight.read().await.get(hrn) {
+            return Arc::clone(lock);
+        }
+
+        let mut in_flight = self.in_flight.write().await;
+        Arc::clone(in_flight.entry(hrn.to_string()).or_insert_with(|| Arc::new(tokio::sync::Mutex::new(()))))
+    }
+
+    /// Resolve `hrn` exactly once even under concurrent callers: serve a
+    /// live cache entry immediately, otherwise hold `hrn`'s lock while
+    /// `resolve` runs so the first caller's result is cached and reused by
+    /// everyone else waiting on the same address instead of each of them
+    /// independently hitting the network
+    ///
+    /// The returned `bool` is whether inserting the freshly resolved entry
+    /// evicted another one to stay within `capacity`, so callers can feed it
+    /// into `Bip353Metrics::record_cache_eviction` alongside TTL-expiry evictions.
+    async fn get_or_resolve<F, Fut>(&self, hrn: &str, resolve: F) -> Result<(PaymentInfo, bool), Bip353Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<(PaymentInfo, Duration), Bip353Error>>,
+    {
+        if let CacheLookup::Hit(info, _age, _ttl) = self.get(hrn).await {
+            return Ok((info, false));
+        }
+
+        let lock = self.lock_for(hrn).await;
+        let _guard = lock.lock().await;
+
+        // Another waiter may have already populated the cache while we
+        // waited for the lock
+        if let CacheLookup::Hit(info, _age, _ttl) = self.get(hrn).await {
+            return Ok((info, false));
+        }
+
+        // `ttl` is the record's own remaining validity window, not a
+        // synthetic constant - see `Bip353Resolver::resolve_with_ttl`
+        let (info, ttl) = resolve().await?;
+        let evicted = self.insert_with_ttl(hrn.to_string(), info.clone(), ttl).await;
+        self.in_flight.write().await.remove(hrn);
+
+        Ok((info, evicted))
+    }
+
+    /// Insert with an explicit TTL, e.g. one derived from the record's own
+    /// DNS TTL, capped to `max_ttl` if one is configured. Returns whether
+    /// inserting this entry evicted the least-recently-used one to stay
+    /// within `capacity`.
+    async fn insert_with_ttl(&self, hrn: String, payment_info: PaymentInfo, ttl: Duration) -> bool {
+        let ttl = match self.max_ttl {
+            Some(max_ttl) => ttl.min(max_ttl),
+            None => ttl,
+        };
+
         let mut entries = self.entries.write().await;
+        let mut evicted_for_capacity = false;
+
+        if let Some(capacity) = self.capacity {
+            if entries.len() >= capacity && !entries.contains_key(&hrn) {
+                if let Some(lru_hrn) = entries.iter().min_by_key(|(_, e)| e.last_accessed).map(|(k, _)| k.clone()) {
+                    entries.remove(&lru_hrn);
+                    evicted_for_capacity = true;
+                }
+            }
+        }
+
+        let now = SystemTime::now();
         entries.insert(hrn, CacheEntry {
             payment_info,
-            cached_at: SystemTime::now(),
-            ttl: self.default_ttl,
+            cached_at: now,
+            last_accessed: now,
+            ttl,
         });
+
+        evicted_for_capacity
     }
-    
+
     async fn invalidate(&self, hrn: &str) {
         let mut entries = self.entries.write().await;
         entries.remove(hrn);
     }
+
+    async fn len(&self) -> usize {
+        self.entries.read().await.len()
+    }
 }
 
 /// BIP-353 resolver - (what's actually needed)
@@ -107,7 +289,12 @@ pub struct Bip353Resolver {
     config: ResolverConfig,
     cache: Option<Arc<AddressCache>>,
     metrics: Option<Arc<Bip353Metrics>>,
-    // Removed: chain_monitor here (not used yet but will be considered in later versions)
+    /// Backend used to detect address reuse, see `with_chain_source`; no
+    /// extra network calls are made while this is `None`
+    chain_source: Option<Arc<dyn ChainSource>>,
+    /// Backend used for optional Lightning route pre-flight probing, see
+    /// `with_payment_backend`; no probing is attempted while this is `None`
+    payment_backend: Option<Arc<dyn PaymentBackend>>,
 }
 
 impl Bip353Resolver {
@@ -118,32 +305,39 @@ impl Bip353Resolver {
     
     /// Create a new resolver with custom configuration
     pub fn with_config(config: ResolverConfig) -> Result<Self, Bip353Error> {
-        Ok(Self { 
+        let cache = config.cache_capacity
+            .map(|capacity| Arc::new(AddressCache::with_capacity_and_max_ttl(DEFAULT_CACHE_TTL, Some(capacity), config.max_cache_ttl)));
+
+        Ok(Self {
             dns_resolver: DNSHrnResolver(config.dns_resolver),
             #[cfg(feature = "http")]
             http_resolver: HTTPHrnResolver,
             resolver_type: ResolverType::DNS,
             config,
-            cache: None,        
-            metrics: None,      
+            cache,
+            metrics: None,
+            chain_source: None,
+            payment_backend: None,
         })
     }
-    
+
     /// Create a new resolver with a specific type
     pub fn with_type(resolver_type: ResolverType) -> Result<Self, Bip353Error> {
         let config = ResolverConfig::default();
-        
-        Ok(Self { 
+
+        Ok(Self {
             dns_resolver: DNSHrnResolver(config.dns_resolver),
             #[cfg(feature = "http")]
             http_resolver: HTTPHrnResolver,
             resolver_type,
             config,
-            cache: None,        
-            metrics: None,      
+            cache: None,
+            metrics: None,
+            chain_source: None,
+            payment_backend: None,
         })
     }
-    
+
     /// Create a new resolver with enhanced features (only cache and metrics)
     pub fn with_enhanced_config(
         config: ResolverConfig,
@@ -152,18 +346,18 @@ impl Bip353Resolver {
         enable_metrics: bool,
     ) -> Result<Self, Bip353Error> {
         let cache = if enable_cache {
-            Some(Arc::new(AddressCache::new(cache_ttl)))
+            Some(Arc::new(AddressCache::with_capacity_and_max_ttl(cache_ttl, None, config.max_cache_ttl)))
         } else {
             None
         };
-        
+
         let metrics = if enable_metrics {
             Some(Arc::new(Bip353Metrics::new()))
         } else {
             None
         };
-        
-        Ok(Self { 
+
+        Ok(Self {
             dns_resolver: DNSHrnResolver(config.dns_resolver),
             #[cfg(feature = "http")]
             http_resolver: HTTPHrnResolver,
@@ -171,11 +365,76 @@ impl Bip353Resolver {
             config,
             cache,
             metrics,
+            chain_source: None,
+            payment_backend: None,
         })
     }
-    
-    /// Resolve a human-readable Bitcoin address
+
+    /// Plug in a `ChainSource` so `resolve_with_safety_checks` can detect
+    /// and warn about address reuse; leave unconfigured to skip those checks
+    /// entirely (the default - no extra network calls are made)
+    pub fn with_chain_source(mut self, chain_source: Arc<dyn ChainSource>) -> Self {
+        self.chain_source = Some(chain_source);
+        self
+    }
+
+    /// Plug in a `PaymentBackend` so `resolve_with_safety_checks_and_probe`
+    /// can pre-flight-probe a Lightning route before returning; leave
+    /// unconfigured to skip probing entirely (the default - no route probe
+    /// is attempted and `SafePaymentInfo::route_probe` stays `None`)
+    pub fn with_payment_backend(mut self, payment_backend: Arc<dyn PaymentBackend>) -> Self {
+        self.payment_backend = Some(payment_backend);
+        self
+    }
+
+    /// Resolve a human-readable Bitcoin address, consulting the cache first
+    /// if one is configured (via `ResolverConfig::with_cache` or
+    /// `with_enhanced_config`), falling back to a fresh DNS+DNSSEC
+    /// resolution on a miss, an expired entry, or when no cache is
+    /// configured at all
+    ///
+    /// Hit/miss/eviction counters are recorded in `CacheStats` only when
+    /// metrics are enabled, which only `with_enhanced_config` does; a cache
+    /// configured through `ResolverConfig::with_cache` records no counters.
     pub async fn resolve(&self, user: &str, domain: &str) -> Result<PaymentInfo, Bip353Error> {
+        let Some(cache) = &self.cache else {
+            return self.resolve_uncached(user, domain).await;
+        };
+
+        let hrn = format!("{}@{}", user, domain);
+
+        match cache.get(&hrn).await {
+            CacheLookup::Hit(info, _age, _ttl) => {
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache_hit();
+                }
+                return Ok(info);
+            },
+            CacheLookup::Expired => {
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache_eviction();
+                    metrics.record_cache_miss();
+                }
+            },
+            CacheLookup::Miss => {
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache_miss();
+                }
+            },
+        }
+
+        let (info, evicted) = cache.get_or_resolve(&hrn, || self.resolve_with_ttl(user, domain)).await?;
+        if evicted {
+            if let Some(metrics) = &self.metrics {
+                metrics.record_cache_eviction();
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Resolve a human-readable Bitcoin address, bypassing the cache entirely
+    async fn resolve_uncached(&self, user: &str, domain: &str) -> Result<PaymentInfo, Bip353Error> {
         // Parse the payment instructions using the appropriate resolver
         let instructions = match self.resolver_type {
             ResolverType::DNS => {
@@ -257,7 +516,7 @@ impl Bip353Resolver {
         };
         
         // Create payment info
-        Ok(PaymentInfo::from_instructions(instructions, uri))
+        Ok(PaymentInfo::from_instructions(instructions, uri, matches!(self.resolver_type, ResolverType::DNS)))
     }
     
     /// Resolve a human-readable Bitcoin address string
@@ -265,65 +524,405 @@ impl Bip353Resolver {
         let (user, domain) = parse_address(address)?;
         self.resolve(&user, &domain).await
     }
+
+    /// Resolve a human-readable Bitcoin address into every payment method
+    /// the record advertises, each as its own `PaymentInfo`, instead of the
+    /// single preferred method `resolve` collapses them into
+    ///
+    /// A record frequently carries an on-chain fallback alongside a reusable
+    /// BOLT12 offer and/or a one-shot BOLT11 invoice; this lets a wallet pick
+    /// whichever rail suits it (cheapest, most private, supported) rather
+    /// than accepting whatever `resolve` happened to prefer.
+    pub async fn resolve_all(&self, user: &str, domain: &str) -> Result<Vec<PaymentInfo>, Bip353Error> {
+        let instructions = match self.resolver_type {
+            ResolverType::DNS => {
+                PaymentInstructions::parse(
+                    &format!("{}@{}", user, domain),
+                    self.config.network,
+                    &self.dns_resolver,
+                    true,
+                ).await.map_err(Bip353Error::from)?
+            },
+            #[cfg(feature = "http")]
+            ResolverType::HTTP => {
+                PaymentInstructions::parse(
+                    &format!("{}@{}", user, domain),
+                    self.config.network,
+                    &self.http_resolver,
+                    true,
+                ).await.map_err(Bip353Error::from)?
+            },
+        };
+
+        let methods = PaymentInfo::from_instructions_all(instructions, matches!(self.resolver_type, ResolverType::DNS));
+        if methods.is_empty() {
+            return Err(Bip353Error::InvalidRecord("No payment methods found".into()));
+        }
+
+        Ok(methods)
+    }
+
+    /// Resolve a human-readable Bitcoin address string into every payment
+    /// method the record advertises; see [`Self::resolve_all`]
+    pub async fn resolve_address_all(&self, address: &str) -> Result<Vec<PaymentInfo>, Bip353Error> {
+        let (user, domain) = parse_address(address)?;
+        self.resolve_all(&user, &domain).await
+    }
+
+    /// Number of addresses currently held in the resolution cache, or 0 if
+    /// caching isn't enabled (see `ResolverConfig::with_cache`)
+    pub async fn cache_len(&self) -> usize {
+        match &self.cache {
+            Some(cache) => cache.len().await,
+            None => 0,
+        }
+    }
+
+    /// Resolve a human-readable Bitcoin address and capture the raw DNSSEC
+    /// proof chain alongside it, for offline/air-gapped verification later
+    /// (see `crate::verify_proof`). Only supported for the DNS resolver,
+    /// since the HTTP fallback doesn't produce a DNSSEC proof.
+    pub async fn resolve_with_proof(&self, user: &str, domain: &str) -> Result<(PaymentInfo, Vec<u8>), Bip353Error> {
+        match self.resolver_type {
+            ResolverType::DNS => {
+                crate::dnssec::resolve_with_proof(self.config.dns_resolver, user, domain).await
+            },
+            #[cfg(feature = "http")]
+            ResolverType::HTTP => {
+                Err(Bip353Error::DnssecError("The HTTP resolver doesn't produce a DNSSEC proof".into()))
+            },
+        }
+    }
+
+    /// Resolve `user@domain` along with how long the result may be cached
+    /// for, carrying the same `original_instructions` a plain `resolve_uncached`
+    /// would so a cached result can still be paid through a `PaymentBackend`
+    /// (see `LdkPaymentBackend::invoice_for`/`offer_for`). For the DNS
+    /// resolver the TTL is the record's own DNS TTL (see
+    /// `crate::dnssec::fetch_ttl`); the HTTP fallback has no such concept,
+    /// so it falls back to `DEFAULT_CACHE_TTL`.
+    async fn resolve_with_ttl(&self, user: &str, domain: &str) -> Result<(PaymentInfo, Duration), Bip353Error> {
+        let info = self.resolve_uncached(user, domain).await?;
+
+        let ttl = match self.resolver_type {
+            ResolverType::DNS => {
+                crate::dnssec::fetch_ttl(self.config.dns_resolver, user, domain).await?
+            },
+            #[cfg(feature = "http")]
+            ResolverType::HTTP => DEFAULT_CACHE_TTL,
+        };
+
+        Ok((info, ttl))
+    }
+
+    /// Resolve a human-readable Bitcoin address string and capture the raw
+    /// DNSSEC proof chain alongside it; see [`Self::resolve_with_proof`]
+    pub async fn resolve_address_with_proof(&self, address: &str) -> Result<(PaymentInfo, Vec<u8>), Bip353Error> {
+        let (user, domain) = parse_address(address)?;
+        self.resolve_with_proof(&user, &domain).await
+    }
+
+    /// Independently re-validate a previously captured DNSSEC proof chain
+    /// against `hrn`, asserting it attests to `expected_txt`, with no
+    /// network access
+    ///
+    /// This is the method form of the free function `crate::verify_proof`,
+    /// for callers that already have a `Bip353Resolver` handy and just want
+    /// a pass/fail answer rather than the reconstructed `PaymentInfo`.
+    pub fn verify_proof(&self, proof: &[u8], hrn: &str, expected_txt: &str) -> Result<(), Bip353Error> {
+        let (user, domain) = parse_address(hrn)?;
+        crate::dnssec::verify_proof(proof, &user, &domain, expected_txt).map(|_| ())
+    }
+
+    /// Resolve a human-readable Bitcoin address for a specific amount
+    ///
+    /// This is needed for addresses that resolve to `ConfigurableAmount`
+    /// instructions (e.g. LNURL-Pay), where the concrete payment method can
+    /// only be determined once the payer's intended amount is known. Returns
+    /// [`Bip353Error::AmountOutOfRange`] if `amount_sat` falls outside the
+    /// bounds the recipient advertised.
+    pub async fn resolve_for_amount(&self, address: &str, amount_sat: u64) -> Result<PaymentInfo, Bip353Error> {
+        let (user, domain) = parse_address(address)?;
+
+        let instructions = match self.resolver_type {
+            ResolverType::DNS => {
+                PaymentInstructions::parse(
+                    &format!("{}@{}", user, domain),
+                    self.config.network,
+                    &self.dns_resolver,
+                    true,
+                ).await.map_err(Bip353Error::from)?
+            },
+            #[cfg(feature = "http")]
+            ResolverType::HTTP => {
+                PaymentInstructions::parse(
+                    &format!("{}@{}", user, domain),
+                    self.config.network,
+                    &self.http_resolver,
+                    true,
+                ).await.map_err(Bip353Error::from)?
+            },
+        };
+
+        let configurable = match instructions {
+            PaymentInstructions::FixedAmount(fixed) => {
+                // Nothing to configure: the address already resolved to a
+                // single amount, so just hand back the concrete PaymentInfo.
+                let uri = Self::uri_for_fixed_amount(&fixed)?;
+                return Ok(PaymentInfo::from_instructions(
+                    PaymentInstructions::FixedAmount(fixed),
+                    uri,
+                    matches!(self.resolver_type, ResolverType::DNS),
+                ));
+            },
+            PaymentInstructions::ConfigurableAmount(configurable) => configurable,
+        };
+
+        if let Some((min, max)) = Self::lnurl_bounds_sats(&configurable) {
+            if amount_sat < min || amount_sat > max {
+                return Err(Bip353Error::AmountOutOfRange { min, max });
+            }
+        }
+
+        let amount = bitcoin_payment_instructions::amount::Amount::from_sats(amount_sat)
+            .map_err(|e| Bip353Error::InvalidAddress(format!("Invalid amount: {}", e)))?;
+
+        let resolver: &dyn bitcoin_payment_instructions::hrn_resolution::HrnResolver = match self.resolver_type {
+            ResolverType::DNS => &self.dns_resolver,
+            #[cfg(feature = "http")]
+            ResolverType::HTTP => &self.http_resolver,
+        };
+
+        let fixed = configurable
+            .resolve_with_amount(amount, resolver)
+            .await
+            .map_err(Bip353Error::from)?;
+
+        let uri = Self::uri_for_fixed_amount(&fixed)?;
+        Ok(PaymentInfo::from_instructions(
+            PaymentInstructions::FixedAmount(fixed),
+            uri,
+            matches!(self.resolver_type, ResolverType::DNS),
+        ))
+    }
+
+    /// Extract the combined min/max sendable bounds (in satoshis) across any
+    /// LNURL-Pay methods present, if there are any to check against
+    fn lnurl_bounds_sats(configurable: &bitcoin_payment_instructions::ConfigurableAmountPaymentInstructions) -> Option<(u64, u64)> {
+        let mut bounds: Option<(u64, u64)> = None;
+
+        for method in configurable.methods() {
+            if let bitcoin_payment_instructions::PossiblyResolvedPaymentMethod::LNURLPay { min_sendable, max_sendable, .. } = method {
+                let min_sats = (min_sendable.milli_sats() + 999) / 1000;
+                let max_sats = max_sendable.milli_sats() / 1000;
+                bounds = Some(match bounds {
+                    Some((min, max)) => (min.max(min_sats), max.min(max_sats)),
+                    None => (min_sats, max_sats),
+                });
+            }
+        }
+
+        bounds
+    }
+
+    /// Build the concrete BIP-21/BOLT11/BOLT12 URI for a resolved, fixed-amount
+    /// payment, preferring Lightning over on-chain when multiple methods are present
+    fn uri_for_fixed_amount(fixed: &bitcoin_payment_instructions::FixedAmountPaymentInstructions) -> Result<String, Bip353Error> {
+        let methods = fixed.methods();
+        let method = methods.iter()
+            .find(|m| matches!(m, bitcoin_payment_instructions::PaymentMethod::LightningBolt11(_)))
+            .or_else(|| methods.iter().find(|m| matches!(m, bitcoin_payment_instructions::PaymentMethod::LightningBolt12(_))))
+            .or_else(|| methods.first())
+            .ok_or_else(|| Bip353Error::InvalidRecord("No payment methods found".into()))?;
+
+        Ok(match method {
+            bitcoin_payment_instructions::PaymentMethod::OnChain(addr) => {
+                let mut uri = format!("bitcoin:{}", addr);
+                if let Some(amount) = fixed.max_amount() {
+                    uri.push_str(&format!("?amount={}", amount.btc_decimal_rounding_up_to_sats()));
+                }
+                uri
+            },
+            bitcoin_payment_instructions::PaymentMethod::LightningBolt11(invoice) => {
+                format!("bitcoin:?lightning={}", invoice)
+            },
+            bitcoin_payment_instructions::PaymentMethod::LightningBolt12(offer) => {
+                format!("bitcoin:?lno={}", offer)
+            },
+        })
+    }
     
     /// Resolve with basic safety checks (cache + warnings)
     pub async fn resolve_with_safety_checks(&self, user: &str, domain: &str) -> Result<SafePaymentInfo, Bip353Error> {
         let hrn = format!("{}@{}", user, domain);
-        
-        // Check cache first
-        if let Some(cache) = &self.cache {
-            if let Some(cached) = cache.get(&hrn).await {
-                // Record cache hit
+
+        let payment_info = if let Some(cache) = &self.cache {
+            match cache.get(&hrn).await {
+                CacheLookup::Hit(cached, age, ttl) => {
+                    if let Some(metrics) = &self.metrics {
+                        metrics.record_cache_hit();
+                    }
+
+                    let warnings = self.check_basic_warnings(&cached, Some((age, ttl))).await;
+
+                    return Ok(SafePaymentInfo {
+                        payment_info: cached,
+                        warnings,
+                        last_checked: SystemTime::now(),
+                        route_probe: None,
+                    });
+                },
+                CacheLookup::Expired => {
+                    if let Some(metrics) = &self.metrics {
+                        metrics.record_cache_eviction();
+                        metrics.record_cache_miss();
+                    }
+                },
+                CacheLookup::Miss => {
+                    if let Some(metrics) = &self.metrics {
+                        metrics.record_cache_miss();
+                    }
+                },
+            }
+
+            // Missed (or evicted): resolve once for this `hrn` even if
+            // several callers race in concurrently, and share the result
+            // with everyone waiting rather than each hitting DNS separately
+            let start_time = std::time::Instant::now();
+            let (payment_info, evicted) = cache.get_or_resolve(&hrn, || self.resolve_with_ttl(user, domain)).await?;
+            let resolution_time = start_time.elapsed();
+
+            if evicted {
                 if let Some(metrics) = &self.metrics {
-                    metrics.record_cache_hit();
+                    metrics.record_cache_eviction();
                 }
-                
-                return Ok(SafePaymentInfo {
-                    payment_info: cached,
-                    warnings: vec![], // No warnings for cached results for now
-                    last_checked: SystemTime::now(),
-                });
-            } else if let Some(metrics) = &self.metrics {
-                metrics.record_cache_miss();
             }
-        }
-        
-        // Resolve using main impl
-        let start_time = std::time::Instant::now();
-        let payment_info = self.resolve(user, domain).await?;
-        let resolution_time = start_time.elapsed();
-        
-        // Cache the result
-        if let Some(cache) = &self.cache {
-            cache.insert(hrn.clone(), payment_info.clone()).await;
-        }
-        
-        // Record metrics
-        if let Some(metrics) = &self.metrics {
-            metrics.record_resolution_success(domain, resolution_time).await;
-        }
-        
-        // Basic warnings (can be extended later)
-        let warnings = self.check_basic_warnings(&payment_info).await;
-        
+
+            if let Some(metrics) = &self.metrics {
+                metrics.record_resolution_success(domain, resolution_time).await;
+            }
+
+            payment_info
+        } else {
+            let start_time = std::time::Instant::now();
+            let payment_info = self.resolve_uncached(user, domain).await?;
+            let resolution_time = start_time.elapsed();
+
+            if let Some(metrics) = &self.metrics {
+                metrics.record_resolution_success(domain, resolution_time).await;
+            }
+
+            payment_info
+        };
+
+        // Basic warnings (can be extended later); this is always a fresh
+        // resolution at this point, so there's no cache age to check
+        let warnings = self.check_basic_warnings(&payment_info, None).await;
+
         Ok(SafePaymentInfo {
             payment_info,
             warnings,
             last_checked: SystemTime::now(),
+            route_probe: None,
         })
     }
-    
-    /// Basic warning checks that don't require blockchain integration
-    async fn check_basic_warnings(&self, _payment_info: &PaymentInfo) -> Vec<AddressWarning> {
-        let warnings = vec![];
-        
-        // Future: Adding basic checks like:
-        // - URI format validation
-        // - Parameter validation
-        // - Network compatibility checks
-        
+
+    /// Resolve with the same cache and warning checks as
+    /// `resolve_with_safety_checks`, plus a Lightning pre-flight route probe
+    /// for `amount_msat` if a `PaymentBackend` is configured (see
+    /// `with_payment_backend`) and the resolved method is BOLT11 or BOLT12
+    ///
+    /// A failed probe never fails the whole resolution: it's recorded in
+    /// `SafePaymentInfo::route_probe` and surfaced as
+    /// `AddressWarning::Unroutable` so a wallet can fall back to another
+    /// method in the same BIP-21 URI (e.g. the on-chain address) instead of
+    /// only discovering at pay-time that no route exists.
+    pub async fn resolve_with_safety_checks_and_probe(
+        &self,
+        user: &str,
+        domain: &str,
+        amount_msat: u64,
+    ) -> Result<SafePaymentInfo, Bip353Error> {
+        let mut safe_info = self.resolve_with_safety_checks(user, domain).await?;
+
+        let is_lightning = matches!(
+            safe_info.payment_info.payment_type,
+            PaymentType::Lightning | PaymentType::LightningOffer
+        );
+
+        if let (Some(backend), true) = (&self.payment_backend, is_lightning) {
+            match backend.probe_route(&safe_info.payment_info, amount_msat).await {
+                Ok(fee_msat) => {
+                    safe_info.route_probe = Some(RouteProbeResult { feasible: true, fee_msat: Some(fee_msat) });
+                },
+                Err(e) => {
+                    safe_info.route_probe = Some(RouteProbeResult { feasible: false, fee_msat: None });
+                    safe_info.warnings.push(AddressWarning::Unroutable { reason: e.to_string() });
+                },
+            }
+        }
+
+        Ok(safe_info)
+    }
+
+    /// Basic warning checks: on-chain address reuse (when a `ChainSource` is
+    /// configured), missing DNSSEC proof capture, and - for results served
+    /// from the cache - staleness relative to the entry's own TTL
+    ///
+    /// `cache_age` is `Some((age, ttl))` for a cache hit and `None` for a
+    /// fresh resolution; address reuse is network-bound, so it's only
+    /// checked on a fresh resolution, while staleness only makes sense for
+    /// something that came out of the cache.
+    async fn check_basic_warnings(
+        &self,
+        payment_info: &PaymentInfo,
+        cache_age: Option<(Duration, Duration)>,
+    ) -> Vec<AddressWarning> {
+        let mut warnings = vec![];
+
+        match cache_age {
+            Some((age, ttl)) => {
+                let stale_after = ttl.mul_f64(self.config.stale_record_fraction.clamp(0.0, 1.0));
+                if age >= stale_after {
+                    warnings.push(AddressWarning::StaleRecord { age });
+                }
+            },
+            None => {
+                if payment_info.payment_type == PaymentType::OnChain {
+                    if let Some(tx_id) = self.check_address_reused(payment_info).await {
+                        if let Some(metrics) = &self.metrics {
+                            metrics.record_address_reuse();
+                        }
+                        warnings.push(AddressWarning::AddressReused { tx_id });
+                    }
+                }
+            },
+        }
+
+        if self.config.enforce_dnssec && !payment_info.dnssec_validated {
+            warnings.push(AddressWarning::DnssecWarning {
+                message: "This resolution did not go through DNSSEC validation (served by a \
+                          non-validating path, e.g. the HTTP/LN-Address fallback)".into(),
+            });
+        }
+
         warnings
     }
+
+    /// Query the configured `ChainSource` (if any) for the most recent
+    /// confirmed transaction paying `payment_info`'s on-chain address
+    async fn check_address_reused(&self, payment_info: &PaymentInfo) -> Option<String> {
+        let chain_source = self.chain_source.as_ref()?;
+
+        let address_str = payment_info.uri.strip_prefix("bitcoin:")?.split('?').next()?;
+        let address = bitcoin::Address::from_str(address_str).ok()?
+            .require_network(self.config.network).ok()?;
+
+        match chain_source.address_history(&address.script_pubkey()).await {
+            Ok(history) => history.last().map(|txid| txid.to_string()),
+            Err(_) => None, // a chain source hiccup shouldn't fail the whole resolution
+        }
+    }
     
     /// Clear cache
     pub async fn clear_cache(&self) {
@@ -0,0 +1,163 @@
+//! JSON-RPC daemon subsystem for out-of-process resolution
+//!
+//! The FFI bindings cover callers that can link the Rust/C ABI directly
+//! (e.g. Bitcoin Core), but some integrations - wallets in other languages,
+//! or anyone who wants the DNSSEC-validating resolver running in its own
+//! sandboxed process - would rather talk to it over a socket. `RpcServer`
+//! wraps a [`Bip353Resolver`] behind a small line-delimited JSON protocol,
+//! mirroring the `--json` convention used by comparable Lightning swap
+//! daemons: one JSON object per request line in, one JSON object per
+//! response line out.
+//!
+//! Request shapes:
+//!
+//! ```text
+//! {"address": "₿user@domain"}
+//! {"user": "user", "domain": "domain"}
+//! ```
+//!
+//! Response shapes:
+//!
+//! ```text
+//! {"uri": "...", "payment_type": "...", "is_reusable": true, "parameters": {...}}
+//! {"error": {"code": "invalid_address", "message": "..."}}
+//! ```
+
+use std::io::Error as IoError;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+#[cfg(unix)]
+use tokio::net::UnixListener;
+
+use crate::{Bip353Error, Bip353Resolver, PaymentInfo};
+
+/// A JSON-RPC server exposing `Bip353Resolver::resolve` to out-of-process callers
+pub struct RpcServer {
+    resolver: Arc<Bip353Resolver>,
+}
+
+impl RpcServer {
+    /// Wrap a resolver for serving over a socket
+    pub fn new(resolver: Arc<Bip353Resolver>) -> Self {
+        Self { resolver }
+    }
+
+    /// Accept connections on a TCP listener until it's closed or the process exits
+    pub async fn serve_tcp(&self, listener: TcpListener) -> Result<(), IoError> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let resolver = Arc::clone(&self.resolver);
+            let (reader, writer) = stream.into_split();
+            tokio::spawn(async move {
+                let mut writer = writer;
+                if let Err(e) = serve_connection(reader, &mut writer, resolver).await {
+                    eprintln!("bip353 rpc connection error: {}", e);
+                }
+            });
+        }
+    }
+
+    /// Accept connections on a Unix domain socket listener until it's closed
+    /// or the process exits
+    #[cfg(unix)]
+    pub async fn serve_unix(&self, listener: UnixListener) -> Result<(), IoError> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let resolver = Arc::clone(&self.resolver);
+            let (reader, writer) = stream.into_split();
+            tokio::spawn(async move {
+                let mut writer = writer;
+                if let Err(e) = serve_connection(reader, &mut writer, resolver).await {
+                    eprintln!("bip353 rpc connection error: {}", e);
+                }
+            });
+        }
+    }
+}
+
+/// Read one JSON-RPC request per line, resolve it, and write one JSON
+/// response line back, until the connection closes
+async fn serve_connection<R, W>(reader: R, writer: &mut W, resolver: Arc<Bip353Resolver>) -> Result<(), IoError>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = handle_request_line(&line, &resolver).await;
+
+        writer.write_all(response.to_string().as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        writer.flush().await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct ResolveRequest {
+    address: Option<String>,
+    user: Option<String>,
+    domain: Option<String>,
+}
+
+async fn handle_request_line(line: &str, resolver: &Bip353Resolver) -> Value {
+    let request: ResolveRequest = match serde_json::from_str(line) {
+        Ok(req) => req,
+        Err(e) => return error_response("invalid_request", &e.to_string()),
+    };
+
+    let (user, domain) = match (request.address, request.user, request.domain) {
+        (Some(address), _, _) => match crate::parse_address(&address) {
+            Ok(parts) => parts,
+            Err(e) => return error_to_json(&e),
+        },
+        (None, Some(user), Some(domain)) => (user, domain),
+        _ => return error_response("invalid_request", "request must set \"address\" or both \"user\" and \"domain\""),
+    };
+
+    match resolver.resolve(&user, &domain).await {
+        Ok(info) => payment_info_to_json(&info),
+        Err(e) => error_to_json(&e),
+    }
+}
+
+fn payment_info_to_json(info: &PaymentInfo) -> Value {
+    json!({
+        "uri": info.uri,
+        "payment_type": info.payment_type.to_string(),
+        "is_reusable": info.is_reusable,
+        "parameters": info.parameters,
+    })
+}
+
+fn error_to_json(err: &Bip353Error) -> Value {
+    error_response(error_code(err), &err.to_string())
+}
+
+fn error_response(code: &str, message: &str) -> Value {
+    json!({ "error": { "code": code, "message": message } })
+}
+
+/// Stable machine-readable code for each `Bip353Error` variant, so RPC
+/// clients can branch on the error kind without parsing the message text
+fn error_code(err: &Bip353Error) -> &'static str {
+    match err {
+        Bip353Error::DnsError(_) => "dns_error",
+        Bip353Error::InvalidAddress(_) => "invalid_address",
+        Bip353Error::InvalidRecord(_) => "invalid_record",
+        Bip353Error::DnssecError(_) => "dnssec_error",
+        Bip353Error::ImplError(_) => "impl_error",
+        Bip353Error::NetworkError(_) => "network_error",
+        Bip353Error::AmountOutOfRange { .. } => "amount_out_of_range",
+    }
+}
@@ -1,12 +1,16 @@
 //! Type definitions for BIP-353 integrations
 
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 use bitcoin_payment_instructions::{
-    PaymentInstructions, 
-    PaymentMethod, 
-    FixedAmountPaymentInstructions, 
+    PaymentInstructions,
+    PaymentMethod,
+    PossiblyResolvedPaymentMethod,
+    FixedAmountPaymentInstructions,
     ConfigurableAmountPaymentInstructions
 };
+use bitcoin_payment_instructions::amount::Amount;
+use lightning_invoice::Bolt11Invoice;
 
 /// Payment instruction type
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -40,18 +44,222 @@ impl std::fmt::Display for PaymentType {
 pub struct PaymentInfo {
     /// The Bitcoin URI (BIP-21)
     pub uri: String,
-    
+
     /// The type of payment method
     pub payment_type: PaymentType,
-    
+
     /// Whether the payment address is reusable
     pub is_reusable: bool,
-    
+
     /// Additional parameters from the payment URI
     pub parameters: HashMap<String, String>,
-    
-    /// Original payment instructions
-    pub original_instructions: OriginalInstructions,
+
+    /// Decoded BOLT11 invoice fields, present whenever the instructions carry
+    /// a BOLT11 invoice, even if `payment_type` reports another method
+    pub bolt11_details: Option<Bolt11Details>,
+
+    /// Decoded BOLT12 offer fields, present whenever the instructions carry
+    /// a BOLT12 offer, even if `payment_type` reports another method
+    pub offer_details: Option<OfferDetails>,
+
+    /// The serialized DNSSEC proof chain this was resolved with, if the
+    /// resolver was asked to capture one (see `Bip353Resolver::resolve_with_proof`)
+    pub dnssec_proof: Option<Vec<u8>>,
+
+    /// Whether this resolution actually went through DNSSEC validation -
+    /// true for the DNS resolver (which validates internally even when no
+    /// proof was captured for export) and for anything reconstructed from a
+    /// verified proof, false for the HTTP/LN-Address fallback, which has no
+    /// DNSSEC to validate. This is distinct from `dnssec_proof`, which is
+    /// only `Some` when the proof bytes were explicitly captured for
+    /// offline re-verification.
+    pub dnssec_validated: bool,
+
+    /// Original payment instructions, absent when this `PaymentInfo` was
+    /// reconstructed offline from a verified DNSSEC proof rather than a live
+    /// resolution (see `verify_proof`)
+    pub original_instructions: Option<OriginalInstructions>,
+}
+
+/// A single hop in a BOLT11 private route hint
+#[derive(Debug, Clone)]
+pub struct RouteHintHop {
+    /// Node id of the hop's source node
+    pub src_node_id: String,
+
+    /// Short channel id of the hop's channel
+    pub short_channel_id: u64,
+
+    /// Base fee charged by the hop, in millisatoshis
+    pub fee_base_msat: u32,
+
+    /// Proportional fee charged by the hop, in millionths of the amount
+    pub fee_proportional_millionths: u32,
+
+    /// CLTV expiry delta added by the hop
+    pub cltv_expiry_delta: u16,
+
+    /// Minimum HTLC size accepted by the hop, in millisatoshis
+    pub htlc_minimum_msat: Option<u64>,
+
+    /// Maximum HTLC size accepted by the hop, in millisatoshis
+    pub htlc_maximum_msat: Option<u64>,
+}
+
+/// Structured fields decoded from a BOLT11 invoice, so wallets can inspect
+/// (and verify) amount/expiry/payee without re-parsing the opaque URI
+#[derive(Debug, Clone)]
+pub struct Bolt11Details {
+    /// The amount requested by the invoice, or `None` for an "any amount" invoice
+    pub amount_msat: Option<u64>,
+
+    /// The payment hash, hex-encoded
+    pub payment_hash: String,
+
+    /// The payment secret, hex-encoded
+    pub payment_secret: String,
+
+    /// The plaintext description, if the invoice carries one directly
+    pub description: Option<String>,
+
+    /// The hash of the description, if the invoice only carries a description hash
+    pub description_hash: Option<String>,
+
+    /// When the invoice was created
+    pub timestamp: SystemTime,
+
+    /// How long after `timestamp` the invoice is valid for (defaults to 3600s)
+    pub expiry: Duration,
+
+    /// The minimum `cltv_expiry_delta` the payee requires for the final hop
+    pub min_final_cltv_expiry_delta: u64,
+
+    /// The payee's node id, hex-encoded, when recoverable from the invoice
+    pub payee_pubkey: Option<String>,
+
+    /// Private route hints the payee included to reach otherwise-unannounced channels
+    pub route_hints: Vec<Vec<RouteHintHop>>,
+}
+
+impl Bolt11Details {
+    /// Decode the structured fields out of a parsed BOLT11 invoice
+    pub fn from_invoice(invoice: &Bolt11Invoice) -> Self {
+        use lightning_invoice::Bolt11InvoiceDescription;
+
+        let (description, description_hash) = match invoice.description() {
+            Bolt11InvoiceDescription::Direct(desc) => (Some(desc.to_string()), None),
+            Bolt11InvoiceDescription::Hash(hash) => (None, Some(hex_encode(&hash.0.as_ref()[..]))),
+        };
+
+        let route_hints = invoice.route_hints().into_iter().map(|hint| {
+            hint.0.into_iter().map(|hop| RouteHintHop {
+                src_node_id: hex_encode(&hop.src_node_id.serialize()),
+                short_channel_id: hop.short_channel_id,
+                fee_base_msat: hop.fees.base_msat,
+                fee_proportional_millionths: hop.fees.proportional_millionths,
+                cltv_expiry_delta: hop.cltv_expiry_delta,
+                htlc_minimum_msat: hop.htlc_minimum_msat,
+                htlc_maximum_msat: hop.htlc_maximum_msat,
+            }).collect()
+        }).collect();
+
+        Bolt11Details {
+            amount_msat: invoice.amount_milli_satoshis(),
+            payment_hash: hex_encode(invoice.payment_hash().as_ref()),
+            payment_secret: hex_encode(&invoice.payment_secret().0),
+            description,
+            description_hash,
+            timestamp: invoice.timestamp(),
+            expiry: invoice.expiry_time(),
+            min_final_cltv_expiry_delta: invoice.min_final_cltv_expiry_delta(),
+            payee_pubkey: invoice.payee_pub_key().map(|pk| hex_encode(&pk.serialize())),
+            route_hints,
+        }
+    }
+
+    /// Whether the invoice has already expired, relative to now
+    pub fn is_expired(&self) -> bool {
+        match self.timestamp.elapsed() {
+            Ok(elapsed) => elapsed > self.expiry,
+            Err(_) => false,
+        }
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Structured fields decoded from a BOLT12 offer, so wallets can display the
+/// offer (and know whether they need to prompt for an amount/quantity)
+/// without re-parsing the opaque `lno=` string
+#[derive(Debug, Clone)]
+pub struct OfferDetails {
+    /// The fixed amount requested by the offer, in millisatoshis
+    ///
+    /// `None` either because the offer lets the payer pick an amount, or
+    /// because the offer is denominated in a non-Bitcoin currency that needs
+    /// external conversion; see `amount_is_configurable` to tell those apart.
+    pub amount_msat: Option<u64>,
+
+    /// Whether the payer must choose the amount when requesting an invoice
+    pub amount_is_configurable: bool,
+
+    /// The offer's description
+    pub description: Option<String>,
+
+    /// The offer's issuer, if set
+    pub issuer: Option<String>,
+
+    /// When the offer stops being valid
+    pub absolute_expiry: Option<SystemTime>,
+
+    /// The maximum quantity of the item that can be requested in one invoice,
+    /// or `None` if the offer doesn't cap the quantity
+    pub quantity_max: Option<u64>,
+
+    /// Genesis block hashes of the chains this offer is valid for, hex-encoded
+    pub chains: Vec<String>,
+
+    /// The node id that will sign the invoice for this offer, hex-encoded
+    pub signing_pubkey: Option<String>,
+
+    /// The raw bech32 offer string, kept around so a wallet can hand it to an
+    /// invoice-request flow without needing the blinded paths re-encoded here
+    pub offer_bech32: String,
+}
+
+impl OfferDetails {
+    /// Decode the structured fields out of a parsed BOLT12 offer
+    pub fn from_offer(offer: &lightning::offers::offer::Offer) -> Self {
+        use lightning::offers::offer::{Amount, Quantity};
+
+        let (amount_msat, amount_is_configurable) = match offer.amount() {
+            Some(Amount::Bitcoin { amount_msats }) => (Some(amount_msats), false),
+            Some(Amount::Currency { .. }) => (None, false),
+            None => (None, true),
+        };
+
+        let quantity_max = match offer.supported_quantity() {
+            Quantity::One => Some(1),
+            Quantity::Bounded(max) => Some(max.get()),
+            Quantity::Unbounded => None,
+        };
+
+        OfferDetails {
+            amount_msat,
+            amount_is_configurable,
+            description: offer.description().map(|d| d.to_string()),
+            issuer: offer.issuer().map(|i| i.to_string()),
+            absolute_expiry: offer.absolute_expiry().map(|d| {
+                SystemTime::UNIX_EPOCH + d
+            }),
+            quantity_max,
+            chains: offer.chains().iter().map(|c| hex_encode(c.as_bytes())).collect(),
+            signing_pubkey: offer.signing_pubkey().map(|pk| hex_encode(&pk.serialize())),
+            offer_bech32: offer.to_string(),
+        }
+    }
 }
 
 /// Original payment instructions from the underlying implementation
@@ -75,11 +283,17 @@ impl From<PaymentInstructions> for OriginalInstructions {
 
 impl PaymentInfo {
     /// Create a new PaymentInfo from PaymentInstructions
-    pub fn from_instructions(instructions: PaymentInstructions, uri: String) -> Self {
+    ///
+    /// `dnssec_validated` should reflect whether `instructions` came from a
+    /// DNSSEC-validating resolution path (DNS) or not (the HTTP/LN-Address
+    /// fallback); see `PaymentInfo::dnssec_validated`.
+    pub fn from_instructions(instructions: PaymentInstructions, uri: String, dnssec_validated: bool) -> Self {
         let mut parameters = HashMap::new();
         let mut payment_type = PaymentType::Unknown;
         let mut is_reusable = true;
-        
+        let mut bolt11_details = None;
+        let mut offer_details = None;
+
         // Parse payment type and reusability
         match &instructions {
             PaymentInstructions::FixedAmount(fixed) => {
@@ -88,12 +302,15 @@ impl PaymentInfo {
                         PaymentMethod::OnChain(_) => {
                             payment_type = PaymentType::OnChain;
                         },
-                        PaymentMethod::LightningBolt11(_) => {
+                        PaymentMethod::LightningBolt11(invoice) => {
                             payment_type = PaymentType::Lightning;
                             is_reusable = false;
+                            bolt11_details = Some(Bolt11Details::from_invoice(invoice));
                         },
-                        PaymentMethod::LightningBolt12(_) => {
+                        PaymentMethod::LightningBolt12(offer) => {
+                            // BOLT12 offers are reusable by design, so leave `is_reusable` alone
                             payment_type = PaymentType::LightningOffer;
+                            offer_details = Some(OfferDetails::from_offer(offer));
                         },
                     }
                 }
@@ -110,12 +327,14 @@ impl PaymentInfo {
                                 PaymentMethod::OnChain(_) => {
                                     payment_type = PaymentType::OnChain;
                                 },
-                                PaymentMethod::LightningBolt11(_) => {
+                                PaymentMethod::LightningBolt11(invoice) => {
                                     payment_type = PaymentType::Lightning;
                                     is_reusable = false;
+                                    bolt11_details = Some(Bolt11Details::from_invoice(invoice));
                                 },
-                                PaymentMethod::LightningBolt12(_) => {
+                                PaymentMethod::LightningBolt12(offer) => {
                                     payment_type = PaymentType::LightningOffer;
+                                    offer_details = Some(OfferDetails::from_offer(offer));
                                 },
                             }
                         },
@@ -123,25 +342,173 @@ impl PaymentInfo {
                 }
             },
         }
-        
+
         // Parse parameters from URI
-        if let Some(query_start) = uri.find('?') {
-            let query = &uri[query_start+1..];
-            for pair in query.split('&') {
-                if let Some(eq_pos) = pair.find('=') {
-                    let key = pair[..eq_pos].to_string();
-                    let value = pair[eq_pos+1..].to_string();
-                    parameters.insert(key, value);
-                }
-            }
+        for (key, value) in parse_uri_parameters(&uri) {
+            parameters.insert(key, value);
         }
-        
+
         PaymentInfo {
             uri,
             payment_type,
             is_reusable,
             parameters,
-            original_instructions: instructions.into(),
+            bolt11_details,
+            offer_details,
+            dnssec_proof: None,
+            dnssec_validated,
+            original_instructions: Some(instructions.into()),
+        }
+    }
+
+    /// Resolve every payment method in a record into its own `PaymentInfo`,
+    /// instead of collapsing them into a single preferred URI the way
+    /// `from_instructions` does. A BIP-353 record commonly advertises an
+    /// on-chain fallback alongside a reusable BOLT12 offer and/or a one-shot
+    /// BOLT11 invoice; callers that want to choose the cheapest or most
+    /// private rail themselves should use this instead of `Bip353Resolver::resolve`.
+    ///
+    /// `dnssec_validated` carries the same meaning as in `from_instructions`.
+    pub fn from_instructions_all(instructions: PaymentInstructions, dnssec_validated: bool) -> Vec<Self> {
+        let methods: Vec<MethodInfo> = match &instructions {
+            PaymentInstructions::FixedAmount(fixed) => {
+                let max_amount = fixed.max_amount();
+                fixed.methods().iter()
+                    .map(|method| classify_method(method, max_amount.as_ref()))
+                    .collect()
+            },
+            PaymentInstructions::ConfigurableAmount(configurable) => {
+                configurable.methods()
+                    .map(|method| match method {
+                        PossiblyResolvedPaymentMethod::LNURLPay { .. } => MethodInfo {
+                            payment_type: PaymentType::Lightning,
+                            uri: "bitcoin:".to_string(),
+                            is_reusable: false,
+                            bolt11_details: None,
+                            offer_details: None,
+                        },
+                        PossiblyResolvedPaymentMethod::Resolved(method) => classify_method(method, None),
+                    })
+                    .collect()
+            },
+        };
+
+        methods.into_iter().map(|m| PaymentInfo {
+            parameters: parse_uri_parameters(&m.uri),
+            uri: m.uri,
+            payment_type: m.payment_type,
+            is_reusable: m.is_reusable,
+            bolt11_details: m.bolt11_details,
+            offer_details: m.offer_details,
+            dnssec_proof: None,
+            dnssec_validated,
+            original_instructions: Some(instructions.clone().into()),
+        }).collect()
+    }
+
+    /// Build a `PaymentInfo` directly from an already-verified BIP-21 URI,
+    /// with no `PaymentInstructions` to match against (there's no live
+    /// resolver to re-run parsing through)
+    ///
+    /// Used to reconstruct a payment instruction from a captured DNSSEC
+    /// proof; see `verify_proof`.
+    pub fn from_verified_uri(uri: String) -> Result<Self, crate::Bip353Error> {
+        use std::str::FromStr;
+
+        let parameters = parse_uri_parameters(&uri);
+        let mut payment_type = PaymentType::OnChain;
+        let mut is_reusable = true;
+        let mut bolt11_details = None;
+        let mut offer_details = None;
+
+        if let Some(invoice_str) = parameters.get("lightning") {
+            let invoice = Bolt11Invoice::from_str(invoice_str)
+                .map_err(|e| crate::Bip353Error::InvalidRecord(format!("Invalid Lightning invoice: {}", e)))?;
+            payment_type = PaymentType::Lightning;
+            is_reusable = false;
+            bolt11_details = Some(Bolt11Details::from_invoice(&invoice));
+        } else if let Some(offer_str) = parameters.get("lno") {
+            let offer = lightning::offers::offer::Offer::from_str(offer_str)
+                .map_err(|e| crate::Bip353Error::InvalidRecord(format!("Invalid Lightning offer: {:?}", e)))?;
+            payment_type = PaymentType::LightningOffer;
+            offer_details = Some(OfferDetails::from_offer(&offer));
+        } else if !uri.starts_with("bitcoin:") {
+            payment_type = PaymentType::Unknown;
+        }
+
+        Ok(PaymentInfo {
+            uri,
+            payment_type,
+            is_reusable,
+            parameters,
+            bolt11_details,
+            offer_details,
+            dnssec_proof: None,
+            dnssec_validated: true,
+            original_instructions: None,
+        })
+    }
+}
+
+/// The per-method fields `from_instructions_all` needs before it can
+/// assemble a full `PaymentInfo` (parameters are derived from `uri` once
+/// all methods are classified, so they're not included here)
+struct MethodInfo {
+    payment_type: PaymentType,
+    uri: String,
+    is_reusable: bool,
+    bolt11_details: Option<Bolt11Details>,
+    offer_details: Option<OfferDetails>,
+}
+
+/// Classify a single resolved `PaymentMethod` into its own BIP-21 URI and
+/// decoded details, independent of whatever other methods the record offers
+fn classify_method(method: &PaymentMethod, max_amount: Option<&Amount>) -> MethodInfo {
+    match method {
+        PaymentMethod::OnChain(addr) => {
+            let mut uri = format!("bitcoin:{}", addr);
+            if let Some(amount) = max_amount {
+                uri.push_str(&format!("?amount={}", amount.btc_decimal_rounding_up_to_sats()));
+            }
+            MethodInfo {
+                payment_type: PaymentType::OnChain,
+                uri,
+                is_reusable: true,
+                bolt11_details: None,
+                offer_details: None,
+            }
+        },
+        PaymentMethod::LightningBolt11(invoice) => MethodInfo {
+            payment_type: PaymentType::Lightning,
+            uri: format!("bitcoin:?lightning={}", invoice),
+            is_reusable: false,
+            bolt11_details: Some(Bolt11Details::from_invoice(invoice)),
+            offer_details: None,
+        },
+        PaymentMethod::LightningBolt12(offer) => MethodInfo {
+            payment_type: PaymentType::LightningOffer,
+            uri: format!("bitcoin:?lno={}", offer),
+            is_reusable: true,
+            bolt11_details: None,
+            offer_details: Some(OfferDetails::from_offer(offer)),
+        },
+    }
+}
+
+/// Parse the `key=value` pairs out of a BIP-21 URI's query string
+fn parse_uri_parameters(uri: &str) -> HashMap<String, String> {
+    let mut parameters = HashMap::new();
+
+    if let Some(query_start) = uri.find('?') {
+        let query = &uri[query_start+1..];
+        for pair in query.split('&') {
+            if let Some(eq_pos) = pair.find('=') {
+                let key = pair[..eq_pos].to_string();
+                let value = pair[eq_pos+1..].to_string();
+                parameters.insert(key, value);
+            }
         }
     }
+
+    parameters
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
-use crate::{PaymentInfo, Bip353Error};
+use crate::{PaymentInfo, Bip353Error, PaymentType};
 use std::collections::HashMap;
+#[cfg(feature = "bdk")]
+use std::str::FromStr;
+#[cfg(feature = "bdk")]
+use std::sync::Mutex;
 
 /// Metadata that wallets can use for BIP-353 payments
 #[derive(Debug, Clone)]
@@ -43,7 +47,7 @@ impl WalletIntegrationHelper {
     ) -> WalletPaymentInfo {
         let mut metadata = WalletMetadata {
             original_address: original_address.to_string(),
-            dnssec_proof: None, 
+            dnssec_proof: payment_info.dnssec_proof.clone(),
             suggested_label: format!("BIP-353: {}", original_address),
             wallet_specific: HashMap::new(),
         };
@@ -148,4 +152,96 @@ impl WalletIntegration for SparrowWallet {
         todo!()
     }
 }
-*/
\ No newline at end of file
+*/
+
+/// Errors from [`BdkWalletIntegration`]
+#[cfg(feature = "bdk")]
+#[derive(Debug, thiserror::Error)]
+pub enum BdkIntegrationError {
+    /// The resolved payment instruction wasn't on-chain, so there's no
+    /// address for a BDK wallet to pay - Lightning payments need a
+    /// Lightning-capable `PaymentBackend` (see `crate::payment`) instead
+    #[error("Can't build an on-chain transaction for a {0} payment instruction")]
+    UnsupportedPaymentType(PaymentType),
+
+    /// The on-chain address embedded in the resolved URI didn't parse
+    #[error("Invalid on-chain address in resolved payment info: {0}")]
+    InvalidAddress(String),
+
+    /// BDK's `TxBuilder` failed to build the transaction
+    #[error("Failed to build transaction: {0}")]
+    TxBuilder(String),
+}
+
+/// Reference [`WalletIntegration`] for BDK wallets
+///
+/// Builds an unsigned PSBT paying the resolved on-chain address with
+/// `TxBuilder`, and stashes the BIP-353 `suggested_label`/BIP-21 `message`
+/// in the PSBT's proprietary field map so downstream signers can show the
+/// same metadata the caller would have gotten from a raw BIP-21 URI.
+#[cfg(feature = "bdk")]
+pub struct BdkWalletIntegration<D: bdk::database::BatchDatabase> {
+    wallet: Mutex<bdk::Wallet<D>>,
+}
+
+#[cfg(feature = "bdk")]
+impl<D: bdk::database::BatchDatabase> BdkWalletIntegration<D> {
+    /// Wrap a BDK wallet for BIP-353 transaction construction
+    pub fn new(wallet: bdk::Wallet<D>) -> Self {
+        Self { wallet: Mutex::new(wallet) }
+    }
+
+    /// The proprietary-key identifier bip353-rs tags its PSBT metadata with,
+    /// per BIP-174's `<prefix><subtype><key data>` proprietary key format
+    fn proprietary_key(subtype: u8) -> bitcoin::psbt::raw::ProprietaryKey {
+        bitcoin::psbt::raw::ProprietaryKey {
+            prefix: b"bip353".to_vec(),
+            subtype,
+            key: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "bdk")]
+impl<D: bdk::database::BatchDatabase> WalletIntegration for BdkWalletIntegration<D> {
+    type Error = BdkIntegrationError;
+    type TransactionOutput = bitcoin::psbt::PartiallySignedTransaction;
+
+    async fn create_bip353_transaction(
+        &self,
+        wallet_info: WalletPaymentInfo,
+        amount: bitcoin::Amount,
+    ) -> Result<Self::TransactionOutput, Self::Error> {
+        if wallet_info.payment_info.payment_type != PaymentType::OnChain {
+            return Err(BdkIntegrationError::UnsupportedPaymentType(wallet_info.payment_info.payment_type));
+        }
+
+        let address_str = wallet_info.payment_info.uri
+            .strip_prefix("bitcoin:")
+            .and_then(|rest| rest.split('?').next())
+            .filter(|addr| !addr.is_empty())
+            .ok_or_else(|| BdkIntegrationError::InvalidAddress("Resolved URI has no on-chain address".into()))?;
+
+        let address = bitcoin::Address::from_str(address_str)
+            .map_err(|e| BdkIntegrationError::InvalidAddress(e.to_string()))?
+            .assume_checked();
+
+        let params = WalletIntegrationHelper::extract_bip21_params(&wallet_info);
+
+        let (mut psbt, _details) = {
+            let wallet = self.wallet.lock().unwrap();
+            let mut builder = wallet.build_tx();
+            builder.add_recipient(address.script_pubkey(), amount.to_sat());
+            builder.finish().map_err(|e| BdkIntegrationError::TxBuilder(e.to_string()))?
+        };
+
+        if let Some(label) = params.get("label") {
+            psbt.proprietary.insert(Self::proprietary_key(0x01), label.clone().into_bytes());
+        }
+        if let Some(message) = params.get("message") {
+            psbt.proprietary.insert(Self::proprietary_key(0x02), message.clone().into_bytes());
+        }
+
+        Ok(psbt)
+    }
+}
\ No newline at end of file
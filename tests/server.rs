@@ -0,0 +1,62 @@
+//! Integration tests for the JSON-RPC daemon subsystem
+#![cfg(feature = "server")]
+
+use std::sync::Arc;
+
+use bip353::server::RpcServer;
+use bip353::Bip353Resolver;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+// Round-trips a request against a domain that can't possibly have a
+// BIP-353 record, so this doesn't require network access to a real
+// address - just DNS resolution failing gracefully and coming back as a
+// structured JSON error.
+#[tokio::test]
+async fn test_resolve_invalid_domain_over_rpc() {
+    let resolver = Arc::new(Bip353Resolver::new().unwrap());
+    let server = RpcServer::new(resolver);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let _ = server.serve_tcp(listener).await;
+    });
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(b"{\"user\": \"nonexistent\", \"domain\": \"example.invalid\"}\n").await.unwrap();
+
+    let mut reader = BufReader::new(stream);
+    let mut response = String::new();
+    reader.read_line(&mut response).await.unwrap();
+
+    let parsed: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert!(parsed.get("error").is_some(), "expected a structured error, got: {}", response);
+}
+
+// Requires a real, currently-resolvable BIP-353 address, so this is
+// ignored by default. Run with `cargo test -- --ignored`.
+#[tokio::test]
+#[ignore]
+async fn test_resolve_real_address_over_rpc() {
+    let resolver = Arc::new(Bip353Resolver::new().unwrap());
+    let server = RpcServer::new(resolver);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let _ = server.serve_tcp(listener).await;
+    });
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(b"{\"address\": \"test@mattcorallo.com\"}\n").await.unwrap();
+
+    let mut reader = BufReader::new(stream);
+    let mut response = String::new();
+    reader.read_line(&mut response).await.unwrap();
+
+    let parsed: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert!(parsed.get("uri").is_some() || parsed.get("error").is_some());
+}